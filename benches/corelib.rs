@@ -0,0 +1,100 @@
+// Benchmark for computing the interfaces of many files sharing the parsed corelib
+use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_syntax::node::SyntaxNode;
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+use src5_rs::interface::get_src5_interface;
+use src5_rs::parser::ast::{get_database_with_starknet_plugin, get_syntax_tree};
+use src5_rs::parser::cairo_base_type::get_cairo_base_types;
+use src5_rs::parser::cairo_enum::{get_cairo_enums_no_corelib, CORELIB_ENUMS};
+use src5_rs::parser::cairo_struct::{get_cairo_structs_no_corelib, CORELIB_STRUCTS};
+use src5_rs::parser::cairo_trait::get_non_generic_traits;
+use src5_rs::type_registry::TypeRegistry;
+
+const FILES_COUNT: usize = 100;
+
+/// Cairo code for a file whose traits use corelib structs and enums
+fn get_file_content(index: usize) -> String {
+    format!(
+        "use starknet::account::Call;
+
+        #[derive(Drop, Serde)]
+        struct Token{index} {{
+            id: u256,
+            owner: ContractAddress,
+        }}
+
+        trait IToken{index} {{
+            fn get_token(id: u256) -> Token{index};
+            fn get_tokens(ids: Span<u256>) -> Array<Token{index}>;
+            fn find(owner: ContractAddress) -> Option<Token{index}>;
+            fn execute(calls: Array<Call>) -> Result<Span<felt252>, felt252>;
+        }}"
+    )
+}
+
+/// Get the registry with the corelib types as before they were parsed once per database,
+/// parsing the corelib definitions again on every call
+fn get_uncached_corelib_registry(db: &RootDatabase) -> TypeRegistry {
+    let mut registry = TypeRegistry::new();
+    for base_type in get_cairo_base_types() {
+        registry.add_base_type(base_type);
+    }
+    let structs_tree = get_syntax_tree(db, CORELIB_STRUCTS.into());
+    for cairo_struct in get_cairo_structs_no_corelib(db, &structs_tree) {
+        registry.add_struct(cairo_struct);
+    }
+    let enums_tree = get_syntax_tree(db, CORELIB_ENUMS.into());
+    for cairo_enum in get_cairo_enums_no_corelib(db, &enums_tree) {
+        registry.add_enum(cairo_enum);
+    }
+    registry
+}
+
+/// Compute the interfaces of every file, with a registry per file as the CLI does
+fn compute_interfaces(
+    db: &RootDatabase,
+    trees: &[SyntaxNode],
+    get_corelib_registry: fn(&RootDatabase) -> TypeRegistry,
+) {
+    for tree in trees {
+        let mut registry = get_corelib_registry(db);
+        registry.register_syntax_tree(db, tree);
+        for cairo_trait in get_non_generic_traits(db, tree) {
+            get_src5_interface(db, &cairo_trait, &registry).unwrap();
+        }
+    }
+}
+
+fn get_parsed_files() -> (RootDatabase, Vec<SyntaxNode>) {
+    let db = get_database_with_starknet_plugin();
+    let trees = (0..FILES_COUNT)
+        .map(|index| get_syntax_tree(&db, get_file_content(index)))
+        .collect();
+    (db, trees)
+}
+
+fn bench_multi_file(c: &mut Criterion) {
+    let mut group = c.benchmark_group("multi_file");
+
+    // The corelib is parsed once and shared by every file
+    group.bench_function("shared_corelib", |b| {
+        b.iter_batched(
+            get_parsed_files,
+            |(db, trees)| compute_interfaces(&db, &trees, TypeRegistry::with_corelib),
+            BatchSize::PerIteration,
+        )
+    });
+
+    // The corelib is parsed again for every file, as before the caching
+    group.bench_function("uncached_corelib", |b| {
+        b.iter_batched(
+            get_parsed_files,
+            |(db, trees)| compute_interfaces(&db, &trees, get_uncached_corelib_registry),
+            BatchSize::PerIteration,
+        )
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_multi_file);
+criterion_main!(benches);
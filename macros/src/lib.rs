@@ -0,0 +1,166 @@
+// Procedural macros computing SRC5 interface ids from Cairo traits at compile time
+use std::path::PathBuf;
+
+use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_syntax::node::SyntaxNode;
+use num_bigint::BigUint;
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::quote;
+use src5_rs::interface::get_src5_interface;
+use src5_rs::parser::ast::{get_database_with_starknet_plugin, get_syntax_tree};
+use src5_rs::parser::cairo_trait::{get_non_generic_traits, CairoNonGenericTrait};
+use src5_rs::parser::utils::get_text_position;
+use src5_rs::selector::get_felt_bytes;
+use src5_rs::src5_type::SRC5Typed;
+use src5_rs::type_registry::TypeRegistry;
+use syn::parse::{Parse, ParseStream};
+use syn::{parse_macro_input, Error, LitStr, Token};
+
+/// Compute the SRC5 interface id of a Cairo trait at compile time, as big-endian bytes.
+/// The trait is given as Cairo source code, or as a file relative to the crate root, and
+/// must be selected with `trait = "..."` when the source code defines many traits:
+///
+/// ```ignore
+/// const IFOO_ID: [u8; 32] = src5_id!("trait IFoo { fn bar(x: felt252); }");
+/// const IACCOUNT_ID: [u8; 32] = src5_id!(file = "cairo/account.cairo", trait = "IAccount");
+/// ```
+#[proc_macro]
+pub fn src5_id(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as Src5IdInput);
+    match get_interface_id(&input) {
+        Ok(id) => {
+            let bytes = get_felt_bytes(&id);
+            match &input.file {
+                // Recompile when the file changes
+                Some(path) => {
+                    let path = path.to_string_lossy();
+                    quote! {{
+                        const _: &[u8] = include_bytes!(#path);
+                        [#(#bytes),*]
+                    }}
+                }
+                None => quote! { [#(#bytes),*] },
+            }
+            .into()
+        }
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+struct Src5IdInput {
+    source: String,
+    /// Absolute path of the file, if the source code was read from a file
+    file: Option<PathBuf>,
+    trait_name: Option<String>,
+    /// Span of the source code or file literal, where the errors are reported
+    span: Span,
+}
+
+impl Parse for Src5IdInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let (source, file, span) = if input.peek(LitStr) {
+            let source: LitStr = input.parse()?;
+            (source.value(), None, source.span())
+        } else {
+            let key: syn::Ident = input.parse()?;
+            if key != "file" {
+                return Err(Error::new(
+                    key.span(),
+                    "Expected Cairo source code or `file = \"...\"`",
+                ));
+            }
+            input.parse::<Token![=]>()?;
+            let file: LitStr = input.parse()?;
+            let root_dir = std::env::var("CARGO_MANIFEST_DIR").unwrap_or_default();
+            let path = PathBuf::from(root_dir).join(file.value());
+            let source = std::fs::read_to_string(&path).map_err(|error| {
+                Error::new(
+                    file.span(),
+                    format!("Failed to read {}: {}", path.display(), error),
+                )
+            })?;
+            (source, Some(path), file.span())
+        };
+
+        let mut trait_name = None;
+        if input.parse::<Option<Token![,]>>()?.is_some() && !input.is_empty() {
+            input.parse::<Token![trait]>()?;
+            input.parse::<Token![=]>()?;
+            trait_name = Some(input.parse::<LitStr>()?.value());
+            input.parse::<Option<Token![,]>>()?;
+        }
+        Ok(Src5IdInput {
+            source,
+            file,
+            trait_name,
+            span,
+        })
+    }
+}
+
+/// Compute the interface id of the selected trait, reporting the unresolved types with
+/// their location in the Cairo source code
+fn get_interface_id(input: &Src5IdInput) -> syn::Result<BigUint> {
+    let error = |message: String| Error::new(input.span, message);
+
+    let db = get_database_with_starknet_plugin();
+    let tree = get_syntax_tree(&db, input.source.clone());
+    let mut registry = TypeRegistry::with_corelib(&db);
+    registry.register_syntax_tree(&db, &tree);
+
+    let mut traits = get_non_generic_traits(&db, &tree);
+    let cairo_trait = match &input.trait_name {
+        Some(trait_name) => traits
+            .into_iter()
+            .find(|cairo_trait| cairo_trait.name == *trait_name)
+            .ok_or_else(|| error(format!("Non generic trait not found: {trait_name}")))?,
+        None if traits.len() == 1 => traits.remove(0),
+        None if traits.is_empty() => return Err(error("No non generic trait found".into())),
+        None => {
+            return Err(error(
+                "Many traits found, select one with `trait = \"...\"`".into(),
+            ))
+        }
+    };
+
+    check_types(&db, &cairo_trait, &registry, &input.source).map_err(error)?;
+    let interface =
+        get_src5_interface(&db, &cairo_trait, &registry).map_err(|e| error(e.to_string()))?;
+    Ok(interface.id)
+}
+
+/// Check that the types of every function can be resolved
+fn check_types(
+    db: &RootDatabase,
+    cairo_trait: &CairoNonGenericTrait,
+    registry: &TypeRegistry,
+    source: &str,
+) -> Result<(), String> {
+    for function in cairo_trait.functions.iter() {
+        for ty in function
+            .inputs_types
+            .iter()
+            .chain(function.return_type.iter())
+        {
+            if let Err(error) = ty.get_src5_type(db, registry) {
+                // Report the type, without the `:` or `->` of the clause
+                let type_node = ty.children(db).last().unwrap_or_else(|| ty.clone());
+                return Err(format!(
+                    "Unresolved type `{}` in {} at {}: {}",
+                    type_node.clone().get_text_without_trivia(db),
+                    function.name,
+                    get_location(db, &type_node, source),
+                    error
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Get the location of a node in the Cairo source code, as `line:column`
+fn get_location(db: &RootDatabase, node: &SyntaxNode, source: &str) -> String {
+    let position = get_text_position(source, node.span_without_trivia(db).start);
+    format!("{}:{}", position.line + 1, position.col + 1)
+}
@@ -0,0 +1,22 @@
+use src5_macros::src5_id;
+
+const ISRC5_ID: [u8; 32] =
+    src5_id!("trait ISRC5 { fn supports_interface(interface_id: felt252) -> bool; }");
+const IACCOUNT_ID: [u8; 32] =
+    src5_id!(file = "../tests/cairo_code/account.cairo", trait = "IAccount");
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+#[test]
+fn computes_interface_ids() {
+    assert_eq!(
+        to_hex(&ISRC5_ID),
+        "03f918d17e5ee77373b56385708f855659a07f75997f365cf87748628532a055"
+    );
+    assert_eq!(
+        to_hex(&IACCOUNT_ID),
+        "009a31f9b549fceee3ca06843cd2e968b5fc8149d80b1221d8e485bc5a66acf1"
+    );
+}
@@ -0,0 +1,133 @@
+// Module for computing the SRC5 information of a Cairo document
+use std::path::Path;
+
+use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_filesystem::span::TextOffset;
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::SyntaxNode;
+use lsp_types::{Diagnostic, DiagnosticSeverity, Position, Range};
+use num_bigint::BigUint;
+use src5_rs::parser::ast::get_file_syntax_tree;
+use src5_rs::parser::cairo_function::CairoNonGenericFunction;
+use src5_rs::parser::cairo_trait::get_non_generic_traits;
+use src5_rs::parser::utils::find_children;
+use src5_rs::selector::get_selector_from_signature;
+use src5_rs::src5_type::SRC5Typed;
+use src5_rs::type_registry::TypeRegistry;
+
+pub struct FunctionInfo {
+    pub name: String,
+    pub name_range: Range,
+    /// None if any of the function types couldn't be resolved
+    pub signature: Option<String>,
+    pub selector: Option<BigUint>,
+}
+
+pub struct TraitInfo {
+    pub name: String,
+    pub name_range: Range,
+    /// None if any of the trait functions couldn't be resolved
+    pub id: Option<BigUint>,
+    pub functions: Vec<FunctionInfo>,
+}
+
+#[derive(Default)]
+pub struct DocumentInfo {
+    pub traits: Vec<TraitInfo>,
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+/// Compute the interface ids and selectors of the non generic traits in the document,
+/// with a diagnostic for each type that couldn't be resolved. The document keeps the file
+/// id of its path in the database, so only its latest content is kept.
+pub fn analyze_document(db: &mut RootDatabase, path: &Path, content: &str) -> DocumentInfo {
+    let tree = get_file_syntax_tree(db, path, content.into());
+    let db = &*db;
+    let mut registry = TypeRegistry::with_corelib(db);
+    registry.register_syntax_tree(db, &tree);
+
+    let mut document = DocumentInfo::default();
+    for cairo_trait in get_non_generic_traits(db, &tree) {
+        let mut functions = Vec::new();
+        for function in cairo_trait.functions.iter() {
+            // Resolve each type on its own, to report every unresolved one
+            let mut resolved = true;
+            for ty in get_function_types(function) {
+                if let Err(error) = ty.get_src5_type(db, &registry) {
+                    resolved = false;
+                    // Report the type expression, without the `:` or `->` of the clause
+                    let type_expr = ty.children(db).last().unwrap_or_else(|| ty.clone());
+                    document.diagnostics.push(Diagnostic {
+                        range: get_range(db, content, &type_expr),
+                        severity: Some(DiagnosticSeverity::ERROR),
+                        source: Some("src5".into()),
+                        message: error.to_string(),
+                        ..Default::default()
+                    });
+                }
+            }
+            let signature = if resolved {
+                function.get_efs_signature(db, &registry).ok()
+            } else {
+                None
+            };
+            let selector = signature.as_deref().map(get_selector_from_signature);
+
+            let declaration_node =
+                find_children(db, &function.node, SyntaxKind::FunctionDeclaration).unwrap();
+            functions.push(FunctionInfo {
+                name: function.name.clone(),
+                name_range: get_name_range(db, content, &declaration_node),
+                signature,
+                selector,
+            });
+        }
+
+        let id = functions
+            .iter()
+            .try_fold(BigUint::from(0u8), |id, function| {
+                Some(id ^ function.selector.as_ref()?)
+            });
+        document.traits.push(TraitInfo {
+            name: cairo_trait.name.clone(),
+            name_range: get_name_range(db, content, &cairo_trait.node),
+            id,
+            functions,
+        });
+    }
+    document
+}
+
+fn get_function_types(function: &CairoNonGenericFunction) -> impl Iterator<Item = &SyntaxNode> {
+    function
+        .inputs_types
+        .iter()
+        .chain(function.return_type.iter())
+}
+
+fn get_name_range(db: &RootDatabase, content: &str, node: &SyntaxNode) -> Range {
+    let id_node = find_children(db, node, SyntaxKind::TerminalIdentifier).unwrap();
+    get_range(db, content, &id_node)
+}
+
+fn get_range(db: &RootDatabase, content: &str, node: &SyntaxNode) -> Range {
+    let span = node.span_without_trivia(db);
+    Range {
+        start: get_position(content, span.start),
+        end: get_position(content, span.end),
+    }
+}
+
+/// Get the LSP position of an offset, with the column counted in UTF-16 code units
+fn get_position(content: &str, offset: TextOffset) -> Position {
+    let index = content.len() - offset.take_from(content).len();
+    let line_start = content[..index].rfind('\n').map_or(0, |i| i + 1);
+    Position::new(
+        content[..index].matches('\n').count() as u32,
+        content[line_start..index].encode_utf16().count() as u32,
+    )
+}
+
+pub fn range_contains(range: &Range, position: &Position) -> bool {
+    range.start <= *position && *position <= range.end
+}
@@ -0,0 +1,353 @@
+mod analysis;
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use anyhow::{Ok, Result};
+use cairo_lang_compiler::db::RootDatabase;
+use lsp_server::{
+    Connection, ErrorCode, ExtractError, Message, Notification, Request, RequestId, Response,
+};
+use lsp_types::notification::{
+    DidChangeTextDocument, DidCloseTextDocument, DidOpenTextDocument,
+    Notification as LspNotification, PublishDiagnostics,
+};
+use lsp_types::request::{CodeLensRequest, HoverRequest, Request as LspRequest};
+use lsp_types::{
+    CodeLens, CodeLensOptions, CodeLensParams, Command, Hover, HoverContents, HoverParams,
+    HoverProviderCapability, MarkupContent, MarkupKind, PublishDiagnosticsParams,
+    ServerCapabilities, TextDocumentSyncCapability, TextDocumentSyncKind, Url,
+};
+use src5_rs::parser::ast::get_database_with_starknet_plugin;
+
+use analysis::{analyze_document, range_contains, DocumentInfo};
+
+/// Language server exposing the SRC5 interface ids and selectors of the traits in the
+/// open Cairo documents, communicating over stdio
+fn main() -> Result<()> {
+    let (connection, io_threads) = Connection::stdio();
+
+    let capabilities = serde_json::to_value(ServerCapabilities {
+        text_document_sync: Some(TextDocumentSyncCapability::Kind(TextDocumentSyncKind::FULL)),
+        hover_provider: Some(HoverProviderCapability::Simple(true)),
+        code_lens_provider: Some(CodeLensOptions {
+            resolve_provider: Some(false),
+        }),
+        ..Default::default()
+    })?;
+    connection.initialize(capabilities)?;
+
+    let mut server = Server {
+        connection,
+        db: get_database_with_starknet_plugin(),
+        documents: HashMap::new(),
+    };
+    server.run()?;
+
+    // The connection must be dropped for the io threads to finish
+    drop(server);
+    io_threads.join()?;
+    Ok(())
+}
+
+struct Server {
+    connection: Connection,
+    db: RootDatabase,
+    documents: HashMap<Url, DocumentInfo>,
+}
+
+impl Server {
+    fn run(&mut self) -> Result<()> {
+        let receiver = self.connection.receiver.clone();
+        for message in &receiver {
+            match message {
+                Message::Request(request) => {
+                    if self.connection.handle_shutdown(&request)? {
+                        return Ok(());
+                    }
+                    self.handle_request(request)?;
+                }
+                Message::Notification(notification) => self.handle_notification(notification)?,
+                Message::Response(_) => {}
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_request(&self, request: Request) -> Result<()> {
+        let id = request.id.clone();
+        let response = match request.method.as_str() {
+            HoverRequest::METHOD => request
+                .extract::<HoverParams>(HoverRequest::METHOD)
+                .map(|(id, params)| Response::new_ok(id, self.hover(params)))
+                .unwrap_or_else(|error| get_invalid_params_response(id, error)),
+            CodeLensRequest::METHOD => request
+                .extract::<CodeLensParams>(CodeLensRequest::METHOD)
+                .map(|(id, params)| Response::new_ok(id, self.code_lens(params)))
+                .unwrap_or_else(|error| get_invalid_params_response(id, error)),
+            _ => Response::new_err(
+                id,
+                ErrorCode::MethodNotFound as i32,
+                format!("Unsupported request: {}", request.method),
+            ),
+        };
+        self.connection.sender.send(Message::Response(response))?;
+        Ok(())
+    }
+
+    fn handle_notification(&mut self, notification: Notification) -> Result<()> {
+        match notification.method.as_str() {
+            DidOpenTextDocument::METHOD => {
+                if let Some(params) = get_notification_params::<DidOpenTextDocument>(notification) {
+                    self.update_document(params.text_document.uri, &params.text_document.text)?;
+                }
+            }
+            DidChangeTextDocument::METHOD => {
+                if let Some(params) = get_notification_params::<DidChangeTextDocument>(notification)
+                {
+                    // With full sync the last change holds the whole document
+                    if let Some(change) = params.content_changes.last() {
+                        self.update_document(params.text_document.uri, &change.text)?;
+                    }
+                }
+            }
+            DidCloseTextDocument::METHOD => {
+                if let Some(params) = get_notification_params::<DidCloseTextDocument>(notification)
+                {
+                    self.documents.remove(&params.text_document.uri);
+                    self.publish_diagnostics(params.text_document.uri, Vec::new())?;
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn update_document(&mut self, uri: Url, content: &str) -> Result<()> {
+        // Documents without a file (ex: untitled ones) are identified by their uri
+        let path = uri
+            .to_file_path()
+            .unwrap_or_else(|_| PathBuf::from(uri.as_str()));
+        let document = analyze_document(&mut self.db, &path, content);
+        let diagnostics = document.diagnostics.clone();
+        self.documents.insert(uri.clone(), document);
+        self.publish_diagnostics(uri, diagnostics)
+    }
+
+    fn publish_diagnostics(&self, uri: Url, diagnostics: Vec<lsp_types::Diagnostic>) -> Result<()> {
+        let params = PublishDiagnosticsParams {
+            uri,
+            diagnostics,
+            version: None,
+        };
+        let notification = Notification::new(PublishDiagnostics::METHOD.into(), params);
+        self.connection
+            .sender
+            .send(Message::Notification(notification))?;
+        Ok(())
+    }
+
+    /// Show the interface id when hovering a trait name, and the SRC5 signature and
+    /// selector when hovering a function name
+    fn hover(&self, params: HoverParams) -> Option<Hover> {
+        let uri = &params.text_document_position_params.text_document.uri;
+        let position = &params.text_document_position_params.position;
+        let document = self.documents.get(uri)?;
+
+        for cairo_trait in document.traits.iter() {
+            if range_contains(&cairo_trait.name_range, position) {
+                let value = match &cairo_trait.id {
+                    Some(id) => format!(
+                        "**{}**\n\nSRC5 interface id: `0x{:x}`",
+                        cairo_trait.name, id
+                    ),
+                    None => format!(
+                        "**{}**\n\nSRC5 interface id: unresolved types",
+                        cairo_trait.name
+                    ),
+                };
+                return Some(markdown_hover(value, cairo_trait.name_range));
+            }
+            for function in cairo_trait.functions.iter() {
+                if range_contains(&function.name_range, position) {
+                    let value = match (&function.signature, &function.selector) {
+                        (Some(signature), Some(selector)) => format!(
+                            "**{}**\n\nSRC5 signature: `{}`\n\nExtended function selector: `0x{:x}`",
+                            function.name, signature, selector
+                        ),
+                        _ => format!("**{}**\n\nSRC5 signature: unresolved types", function.name),
+                    };
+                    return Some(markdown_hover(value, function.name_range));
+                }
+            }
+        }
+        None
+    }
+
+    /// Show the interface id above each trait
+    fn code_lens(&self, params: CodeLensParams) -> Vec<CodeLens> {
+        let Some(document) = self.documents.get(&params.text_document.uri) else {
+            return Vec::new();
+        };
+        document
+            .traits
+            .iter()
+            .map(|cairo_trait| {
+                let title = match &cairo_trait.id {
+                    Some(id) => format!("SRC5 id: 0x{:x}", id),
+                    None => "SRC5 id: unresolved types".into(),
+                };
+                CodeLens {
+                    range: cairo_trait.name_range,
+                    command: Some(Command {
+                        title,
+                        command: String::new(),
+                        arguments: None,
+                    }),
+                    data: None,
+                }
+            })
+            .collect()
+    }
+}
+
+/// Answer a request whose parameters can't be parsed with an error, instead of stopping
+/// the server
+fn get_invalid_params_response(id: RequestId, error: ExtractError<Request>) -> Response {
+    Response::new_err(id, ErrorCode::InvalidParams as i32, error.to_string())
+}
+
+/// Get the parameters of a notification, ignoring the ones that can't be parsed since
+/// notifications can't be answered
+fn get_notification_params<N: LspNotification>(notification: Notification) -> Option<N::Params> {
+    notification
+        .extract(N::METHOD)
+        .map_err(|error| eprintln!("Ignored notification: {error}"))
+        .ok()
+}
+
+fn markdown_hover(value: String, range: lsp_types::Range) -> Hover {
+    Hover {
+        contents: HoverContents::Markup(MarkupContent {
+            kind: MarkupKind::Markdown,
+            value,
+        }),
+        range: Some(range),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use lsp_types::notification::DidOpenTextDocument;
+    use lsp_types::{
+        DidOpenTextDocumentParams, Position, Range, TextDocumentIdentifier, TextDocumentItem,
+        TextDocumentPositionParams,
+    };
+
+    use super::*;
+
+    const ACCOUNT: &str = include_str!("../../../tests/cairo_code/account.cairo");
+    const UNRESOLVED: &str = include_str!("../../../tests/cairo_code/unresolved.cairo");
+
+    /// Get a server connected to an in memory client, with the document open
+    fn get_server(text: &str) -> (Server, Connection, Url) {
+        let (connection, client) = Connection::memory();
+        let mut server = Server {
+            connection,
+            db: get_database_with_starknet_plugin(),
+            documents: HashMap::new(),
+        };
+        let uri = Url::parse("file:///test/lib.cairo").unwrap();
+        let params = DidOpenTextDocumentParams {
+            text_document: TextDocumentItem::new(uri.clone(), "cairo".into(), 0, text.into()),
+        };
+        server
+            .handle_notification(Notification::new(
+                DidOpenTextDocument::METHOD.into(),
+                params,
+            ))
+            .unwrap();
+        (server, client, uri)
+    }
+
+    fn hover(server: &Server, client: &Connection, uri: &Url, line: u32, col: u32) -> Response {
+        let params = HoverParams {
+            text_document_position_params: TextDocumentPositionParams::new(
+                TextDocumentIdentifier::new(uri.clone()),
+                Position::new(line, col),
+            ),
+            work_done_progress_params: Default::default(),
+        };
+        let request = Request::new(RequestId::from(1), HoverRequest::METHOD.into(), params);
+        server.handle_request(request).unwrap();
+        get_response(client)
+    }
+
+    fn get_response(client: &Connection) -> Response {
+        loop {
+            if let Message::Response(response) = client.receiver.try_recv().unwrap() {
+                return response;
+            }
+        }
+    }
+
+    fn get_hover_text(response: Response) -> Option<String> {
+        let hover: Option<Hover> = serde_json::from_value(response.result.unwrap()).unwrap();
+        hover.map(|hover| match hover.contents {
+            HoverContents::Markup(markup) => markup.value,
+            _ => unreachable!(),
+        })
+    }
+
+    #[test]
+    fn hover_shows_interface_id_and_selectors() {
+        let (server, client, uri) = get_server(ACCOUNT);
+
+        let text = get_hover_text(hover(&server, &client, &uri, 3, 8)).unwrap();
+        assert!(text.contains("0x9a31f9b549fceee3ca06843cd2e968b5fc8149d80b1221d8e485bc5a66acf1"));
+
+        let text = get_hover_text(hover(&server, &client, &uri, 8, 10)).unwrap();
+        assert!(text.contains("`supports_interface(u32)->E((),())`"));
+        assert!(text.contains("0x15f049bba08527902e4a1b57a4a12d09f6067265105af28e46e8869156ca60d"));
+
+        // Outside of the trait and function names
+        assert_eq!(get_hover_text(hover(&server, &client, &uri, 8, 30)), None);
+    }
+
+    #[test]
+    fn diagnostics_report_unresolved_types() {
+        let (_server, client, _) = get_server(UNRESOLVED);
+        let Message::Notification(notification) = client.receiver.try_recv().unwrap() else {
+            panic!("Expected the diagnostics notification");
+        };
+        let params: PublishDiagnosticsParams = serde_json::from_value(notification.params).unwrap();
+
+        // The columns are counted in UTF-16 code units, the crab being two of them
+        let ranges: Vec<_> = params
+            .diagnostics
+            .iter()
+            .map(|diagnostic| diagnostic.range)
+            .collect();
+        assert_eq!(
+            ranges,
+            vec![
+                Range::new(Position::new(1, 35), Position::new(1, 40)),
+                Range::new(Position::new(2, 33), Position::new(2, 40)),
+            ]
+        );
+    }
+
+    #[test]
+    fn malformed_requests_are_answered_with_an_error() {
+        let (server, client, _) = get_server(ACCOUNT);
+        let request = Request::new(
+            RequestId::from(1),
+            HoverRequest::METHOD.into(),
+            serde_json::json!({ "position": "invalid" }),
+        );
+        server.handle_request(request).unwrap();
+
+        let error = get_response(&client).error.unwrap();
+        assert_eq!(error.code, ErrorCode::InvalidParams as i32);
+    }
+}
@@ -1,4 +1,4 @@
-use crate::commands::Parse;
+use crate::commands::{Check, Comply, Contracts, Explain, Generate, Lint, Lock, Parse, Verify};
 use clap::{Parser, Subcommand};
 
 #[derive(Parser)]
@@ -6,13 +6,41 @@ use clap::{Parser, Subcommand};
 #[command(version, about, long_about = None)]
 pub struct Cli {
     #[command(subcommand)]
-    pub command: Commands,
+    pub command: Option<Commands>,
 }
 
 #[derive(Debug, Subcommand)]
 pub enum Commands {
     #[clap(about = "Parse a file generating interface signatures for each trait")]
     Parse(Parse),
+    #[clap(about = "Parse a file listing the interfaces implemented by each contract")]
+    Contracts(Contracts),
+    #[clap(about = "Parse a file generating the SRC5 support code of each contract")]
+    Generate(Generate),
+    #[clap(
+        about = "Parse a file checking that each contract registers the interfaces it implements"
+    )]
+    Check(Check),
+    #[clap(about = "Parse a file reporting the SRC5 design pitfalls of the traits")]
+    Lint(Lint),
+    #[clap(
+        about = "Parse a file comparing the traits with the standard interfaces they implement"
+    )]
+    Comply(Comply),
+    #[clap(about = "Parse a file recording the interface id of each trait in the lockfile")]
+    Lock(Lock),
+    #[clap(
+        about = "Parse a file checking that the interface ids recorded in the lockfile are unchanged"
+    )]
+    Verify(Verify),
+    #[clap(about = "Parse two versions of a file explaining the changes of the interface ids")]
+    Explain(Explain),
+}
+
+impl Default for Commands {
+    fn default() -> Self {
+        Commands::Parse(Parse::default())
+    }
 }
 
 #[test]
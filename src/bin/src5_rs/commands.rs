@@ -1,5 +1,21 @@
+mod check;
+mod comply;
+mod contracts;
+mod explain;
+mod generate;
+mod lint;
+mod lock;
 mod parse;
+mod verify;
+pub use check::Check;
+pub use comply::Comply;
+pub use contracts::{ContractInterface, ContractInterfaces, Contracts};
+pub use explain::Explain;
+pub use generate::Generate;
+pub use lint::Lint;
+pub use lock::Lock;
 pub use parse::Parse;
+pub use verify::Verify;
 
 use anyhow::Result;
 use async_trait::async_trait;
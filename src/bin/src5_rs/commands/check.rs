@@ -0,0 +1,197 @@
+use anyhow::{bail, Ok, Result};
+use async_trait::async_trait;
+use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::SyntaxNode;
+use clap::Parser;
+use num_bigint::BigUint;
+use src5_rs::parser::ast::get_database_with_starknet_plugin;
+use src5_rs::parser::cairo_constant::get_cairo_constants;
+use src5_rs::parser::cairo_contract::get_registered_ids;
+use src5_rs::parser::utils::{find_children, get_literal_number_value, get_path_segments};
+use src5_rs::selector::get_selector_from_signature;
+
+use super::contracts::{get_contracts_interfaces, ContractInterfaces};
+use super::generate::ISRC5_SIGNATURE;
+use super::parse::{
+    find_item, get_cairo_sources, get_module_path, load_config_and_inputs, resolve_path, CairoFile,
+    CairoSources,
+};
+use super::CliCommand;
+
+#[derive(Parser, Debug)]
+pub struct Check {
+    #[clap(help = "File path to the Cairo source code (defaults to the configured inputs)")]
+    pub cairo_path: Option<String>,
+    #[clap(
+        short,
+        long,
+        help = "Path to the configuration file (defaults to the src5.toml found upward from the input)"
+    )]
+    pub config: Option<String>,
+    #[clap(
+        long = "extern-type",
+        value_name = "TYPE",
+        help = "Declare an opaque type, with its generic parameters if any (ex: Vault<T>)"
+    )]
+    pub extern_types: Vec<String>,
+}
+
+#[async_trait]
+impl CliCommand for Check {
+    // Parse a file checking that each contract registers the interfaces it implements
+    async fn run(&self) -> Result<()> {
+        let (config, inputs) = load_config_and_inputs(
+            self.cairo_path.as_deref(),
+            self.config.as_deref(),
+            &self.extern_types,
+        )?;
+
+        // Create a new database with the StarkNet plugin
+        let mut db = get_database_with_starknet_plugin();
+
+        let sources = get_cairo_sources(&mut db, &inputs, &config)?;
+        let contracts = get_contracts_interfaces(&db, &sources)?;
+
+        let mut errors_count = 0;
+        for contract in contracts.iter() {
+            let problems = check_contract(&db, &sources, contract);
+            if problems.is_empty() {
+                println!("Contract {}: ok", contract.name);
+                continue;
+            }
+            println!("Contract {}:", contract.name);
+            for problem in problems.iter() {
+                let severity = if problem.is_error { "error" } else { "warning" };
+                println!("  {}: {}: {}", problem.location, severity, problem.message);
+            }
+            errors_count += problems.iter().filter(|problem| problem.is_error).count();
+        }
+        if errors_count > 0 {
+            bail!("Found {} registration errors", errors_count);
+        }
+        Ok(())
+    }
+}
+
+struct Problem {
+    /// Location in the source code, as `path:line:column`
+    location: String,
+    message: String,
+    /// Warnings are reported without failing the check
+    is_error: bool,
+}
+
+/// Compare the interface ids registered by the contract with the ids of the interfaces
+/// it implements. The ISRC5 id is expected to be supported without being registered.
+fn check_contract(
+    db: &RootDatabase,
+    sources: &CairoSources,
+    contract: &ContractInterfaces,
+) -> Vec<Problem> {
+    let Some(file) = sources.files.iter().find(|file| file.path == contract.path) else {
+        return Vec::new();
+    };
+    let location = |node: &SyntaxNode| file.get_location(db, node);
+    let isrc5_id = get_selector_from_signature(ISRC5_SIGNATURE);
+    let mut problems = Vec::new();
+
+    // Resolve the registered ids, through the constants defined in the sources by path
+    let mut constants = Vec::new();
+    for file in sources.files.iter() {
+        for constant in get_cairo_constants(db, &file.tree) {
+            let mut path = get_module_path(db, file, &constant.node);
+            path.push(constant.name);
+            constants.push((path, constant.value));
+        }
+    }
+    let mut registered_ids = Vec::new();
+    for node in get_registered_ids(db, &contract.node) {
+        let text = node.clone().get_text_without_trivia(db);
+        match resolve_id(db, file, &node, &constants) {
+            Some(id) => registered_ids.push((id, text, node)),
+            None => problems.push(Problem {
+                location: location(&node),
+                message: format!("Can't resolve the registered id `{text}`"),
+                is_error: false,
+            }),
+        }
+    }
+
+    // Every implemented interface must be registered
+    for interface in contract.interfaces.iter() {
+        // Report the impls at their name, after the attributes
+        let impl_name_node =
+            find_children(db, &interface.node, SyntaxKind::TerminalIdentifier).unwrap();
+        let trait_name = interface
+            .trait_name
+            .as_deref()
+            .unwrap_or(&interface.impl_name);
+        let Some(src5_interface) = &interface.interface else {
+            problems.push(Problem {
+                location: location(&impl_name_node),
+                message: format!(
+                    "The id of {} ({}) can't be computed, so its registration is not checked",
+                    trait_name, interface.impl_name
+                ),
+                is_error: false,
+            });
+            continue;
+        };
+        if src5_interface.id != isrc5_id
+            && !registered_ids
+                .iter()
+                .any(|(id, _, _)| *id == src5_interface.id)
+        {
+            problems.push(Problem {
+                location: location(&impl_name_node),
+                message: format!(
+                    "{} (0x{:x}) is implemented by {} but not registered",
+                    trait_name, src5_interface.id, interface.impl_name
+                ),
+                is_error: true,
+            });
+        }
+    }
+
+    // Every registered id must be implemented
+    for (id, text, node) in registered_ids.iter() {
+        let implemented = contract.interfaces.iter().any(|interface| {
+            interface
+                .interface
+                .as_ref()
+                .is_some_and(|src5_interface| src5_interface.id == *id)
+        });
+        if *id != isrc5_id && !implemented {
+            problems.push(Problem {
+                location: location(node),
+                message: format!("`{text}` (0x{id:x}) is registered but not implemented"),
+                is_error: true,
+            });
+        }
+    }
+    problems
+}
+
+/// Get the value of a registered id expression of the file, being a number literal or
+/// a constant
+fn resolve_id(
+    db: &RootDatabase,
+    file: &CairoFile,
+    node: &SyntaxNode,
+    constants: &[(Vec<String>, Option<BigUint>)],
+) -> Option<BigUint> {
+    match node.kind(db) {
+        SyntaxKind::TerminalLiteralNumber => {
+            get_literal_number_value(&node.clone().get_text_without_trivia(db))
+        }
+        SyntaxKind::ExprPath => {
+            let path = get_path_segments(db, node);
+            let resolved_path = resolve_path(db, file, node, &path);
+            find_item(constants, &resolved_path, &path)
+                .cloned()
+                .flatten()
+        }
+        _ => None,
+    }
+}
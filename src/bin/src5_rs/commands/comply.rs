@@ -0,0 +1,185 @@
+use anyhow::{bail, Result};
+use async_trait::async_trait;
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use clap::Parser;
+use src5_rs::interface::{get_src5_interface, get_src5_interfaces_from_source, SRC5Interface};
+use src5_rs::parser::ast::get_database_with_starknet_plugin;
+use src5_rs::parser::utils::find_children;
+
+use super::parse::{get_cairo_sources, get_file_traits, get_type_registry, load_config_and_inputs};
+use super::CliCommand;
+
+/// Reference definitions of the SNIP and token standard interfaces
+const REFERENCES: &str = include_str!("comply/references.cairo");
+/// Standard interfaces without a published id, only compared by functions
+const WITHOUT_STANDARD_ID: &[&str] = &["IERC20"];
+
+#[derive(Parser, Debug)]
+pub struct Comply {
+    #[clap(help = "File path to the Cairo source code (defaults to the configured inputs)")]
+    pub cairo_path: Option<String>,
+    #[clap(
+        long = "trait",
+        value_name = "TRAIT",
+        help = "Name of the trait to check (defaults to every trait named as a standard interface)"
+    )]
+    pub trait_name: Option<String>,
+    #[clap(
+        short,
+        long,
+        help = "Name of the standard interface (ex: ISRC6, defaults to the name of the trait)"
+    )]
+    pub standard: Option<String>,
+    #[clap(
+        short,
+        long,
+        help = "Path to the configuration file (defaults to the src5.toml found upward from the input)"
+    )]
+    pub config: Option<String>,
+    #[clap(
+        long = "extern-type",
+        value_name = "TYPE",
+        help = "Declare an opaque type, with its generic parameters if any (ex: Vault<T>)"
+    )]
+    pub extern_types: Vec<String>,
+}
+
+#[async_trait]
+impl CliCommand for Comply {
+    // Parse a file comparing the traits with the standard interfaces they implement
+    async fn run(&self) -> Result<()> {
+        let (config, inputs) = load_config_and_inputs(
+            self.cairo_path.as_deref(),
+            self.config.as_deref(),
+            &self.extern_types,
+        )?;
+        let standards = get_src5_interfaces_from_source(REFERENCES)?;
+        if let Some(standard) = &self.standard {
+            if !standards
+                .iter()
+                .any(|interface| interface.name == *standard)
+            {
+                bail!(
+                    "Unknown standard interface: {} (available: {})",
+                    standard,
+                    get_standard_names(&standards)
+                );
+            }
+        }
+
+        // Create a new database with the StarkNet plugin
+        let mut db = get_database_with_starknet_plugin();
+
+        let sources = get_cairo_sources(&mut db, &inputs, &config)?;
+        let registry = get_type_registry(&db, &sources);
+
+        let mut checked_count = 0;
+        let mut failed_count = 0;
+        for file in sources.files.iter() {
+            for cairo_trait in get_file_traits(&db, file, &config) {
+                // Without a trait name, the traits are named as the standard interface
+                let trait_name = self.trait_name.as_ref().or(self.standard.as_ref());
+                if trait_name.is_some_and(|trait_name| *trait_name != cairo_trait.name) {
+                    continue;
+                }
+                let standard_name = self.standard.as_ref().unwrap_or(&cairo_trait.name);
+                let Some(standard) = standards
+                    .iter()
+                    .find(|interface| interface.name == *standard_name)
+                else {
+                    if self.trait_name.is_some() {
+                        bail!(
+                            "No standard interface named as {} (available: {})",
+                            cairo_trait.name,
+                            get_standard_names(&standards)
+                        );
+                    }
+                    continue;
+                };
+
+                let interface = get_src5_interface(&db, &cairo_trait, &registry)?;
+                let name_node =
+                    find_children(&db, &cairo_trait.node, SyntaxKind::TerminalIdentifier).unwrap();
+                println!(
+                    "{} ({}) against {}:",
+                    cairo_trait.name,
+                    file.get_location(&db, &name_node),
+                    standard.name
+                );
+                let differences = get_differences(&interface, standard);
+                for difference in differences.iter() {
+                    println!("  {difference}");
+                }
+                let has_standard_id = !WITHOUT_STANDARD_ID.contains(&standard.name.as_str());
+                if !differences.is_empty() || (has_standard_id && interface.id != standard.id) {
+                    failed_count += 1;
+                }
+                if !has_standard_id {
+                    println!("  id: 0x{:x} (no standard id)", interface.id);
+                } else if interface.id == standard.id {
+                    println!("  id: 0x{:x} (equals the standard id)", interface.id);
+                } else {
+                    println!(
+                        "  id: 0x{:x} (differs from the standard id 0x{:x})",
+                        interface.id, standard.id
+                    );
+                }
+                checked_count += 1;
+            }
+        }
+
+        if checked_count == 0 {
+            match &self.trait_name {
+                Some(trait_name) => bail!("Trait not found: {}", trait_name),
+                None => bail!(
+                    "No trait named as a standard interface (available: {})",
+                    get_standard_names(&standards)
+                ),
+            }
+        }
+        if failed_count > 0 {
+            bail!("Found {} non compliant traits", failed_count);
+        }
+        Ok(())
+    }
+}
+
+/// Get the missing, extra and signature-mismatched functions of an interface, compared
+/// with the standard one
+fn get_differences(interface: &SRC5Interface, standard: &SRC5Interface) -> Vec<String> {
+    let mut differences = Vec::new();
+    for expected in standard.functions.iter() {
+        match interface
+            .functions
+            .iter()
+            .find(|function| function.name == expected.name)
+        {
+            None => differences.push(format!("missing: {}", expected.signature)),
+            Some(function) if function.signature != expected.signature => {
+                differences.push(format!(
+                    "mismatch: {}\n    expected: {}\n    found:    {}",
+                    expected.name, expected.signature, function.signature
+                ))
+            }
+            Some(_) => {}
+        }
+    }
+    for function in interface.functions.iter() {
+        if !standard
+            .functions
+            .iter()
+            .any(|expected| expected.name == function.name)
+        {
+            differences.push(format!("extra: {}", function.signature));
+        }
+    }
+    differences
+}
+
+fn get_standard_names(standards: &[SRC5Interface]) -> String {
+    standards
+        .iter()
+        .map(|interface| interface.name.as_str())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
@@ -0,0 +1,78 @@
+// Reference definitions of the standard interfaces, as used for computing their ids
+
+// SNIP-5: Standard Interface Detection
+trait ISRC5 {
+    fn supports_interface(interface_id: felt252) -> bool;
+}
+
+// SNIP-6: Standard Account Interface
+struct Call {
+    to: ContractAddress,
+    selector: felt252,
+    calldata: Array<felt252>
+}
+
+trait ISRC6 {
+    fn __execute__(calls: Array<Call>) -> Array<Span<felt252>>;
+    fn __validate__(calls: Array<Call>) -> felt252;
+    fn is_valid_signature(hash: felt252, signature: Array<felt252>) -> felt252;
+}
+
+// ERC20 token, without a standard id as ERC20 contracts don't support SRC5
+trait IERC20 {
+    fn name() -> felt252;
+    fn symbol() -> felt252;
+    fn decimals() -> u8;
+    fn total_supply() -> u256;
+    fn balance_of(account: ContractAddress) -> u256;
+    fn allowance(owner: ContractAddress, spender: ContractAddress) -> u256;
+    fn transfer(recipient: ContractAddress, amount: u256) -> bool;
+    fn transfer_from(sender: ContractAddress, recipient: ContractAddress, amount: u256) -> bool;
+    fn approve(spender: ContractAddress, amount: u256) -> bool;
+}
+
+// ERC721 non fungible token
+trait IERC721 {
+    fn balance_of(account: ContractAddress) -> u256;
+    fn owner_of(token_id: u256) -> ContractAddress;
+    fn safe_transfer_from(
+        from: ContractAddress, to: ContractAddress, token_id: u256, data: Span<felt252>
+    );
+    fn transfer_from(from: ContractAddress, to: ContractAddress, token_id: u256);
+    fn approve(to: ContractAddress, token_id: u256);
+    fn set_approval_for_all(operator: ContractAddress, approved: bool);
+    fn get_approved(token_id: u256) -> ContractAddress;
+    fn is_approved_for_all(owner: ContractAddress, operator: ContractAddress) -> bool;
+}
+
+// ERC721 metadata extension
+trait IERC721Metadata {
+    fn name() -> felt252;
+    fn symbol() -> felt252;
+    fn token_uri(token_id: u256) -> felt252;
+}
+
+// ERC721 receiver
+trait IERC721Receiver {
+    fn on_erc721_received(
+        operator: ContractAddress, from: ContractAddress, token_id: u256, data: Span<felt252>
+    ) -> felt252;
+}
+
+// ERC1155 multi token
+trait IERC1155 {
+    fn balance_of(account: ContractAddress, token_id: u256) -> u256;
+    fn balance_of_batch(accounts: Span<ContractAddress>, token_ids: Span<u256>) -> Span<u256>;
+    fn safe_transfer_from(
+        from: ContractAddress, to: ContractAddress, token_id: u256, value: u256, data: Span<felt252>
+    );
+    fn safe_batch_transfer_from(
+        from: ContractAddress,
+        to: ContractAddress,
+        token_ids: Span<u256>,
+        values: Span<u256>,
+        data: Span<felt252>
+    );
+    fn is_approved_for_all(owner: ContractAddress, operator: ContractAddress) -> bool;
+    fn set_approval_for_all(operator: ContractAddress, approved: bool);
+}
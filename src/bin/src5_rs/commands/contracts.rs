@@ -0,0 +1,226 @@
+use std::path::PathBuf;
+
+use anyhow::{Ok, Result};
+use async_trait::async_trait;
+use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_syntax::node::SyntaxNode;
+use clap::Parser;
+use src5_rs::interface::{get_src5_interface, SRC5Interface};
+use src5_rs::parser::ast::get_database_with_starknet_plugin;
+use src5_rs::parser::cairo_contract::{
+    get_cairo_contracts, get_cairo_impls, CairoImpl, CairoImplAlias,
+};
+use src5_rs::parser::cairo_trait::{
+    get_interface_traits, get_non_generic_traits, CairoNonGenericTrait,
+};
+use src5_rs::type_registry::TypeRegistry;
+
+use super::parse::{
+    find_item, get_cairo_sources, get_module_path, get_type_registry, load_config_and_inputs,
+    resolve_path, CairoFile, CairoSources,
+};
+use super::CliCommand;
+use crate::config::OutputFormat;
+use crate::output::print_contracts;
+
+#[derive(Parser, Debug)]
+pub struct Contracts {
+    #[clap(help = "File path to the Cairo source code (defaults to the configured inputs)")]
+    pub cairo_path: Option<String>,
+    #[clap(
+        short,
+        long,
+        value_enum,
+        help = "Output format (defaults to the configured one)"
+    )]
+    pub format: Option<OutputFormat>,
+    #[clap(
+        short,
+        long,
+        help = "Path to the configuration file (defaults to the src5.toml found upward from the input)"
+    )]
+    pub config: Option<String>,
+    #[clap(
+        long = "extern-type",
+        value_name = "TYPE",
+        help = "Declare an opaque type, with its generic parameters if any (ex: Vault<T>)"
+    )]
+    pub extern_types: Vec<String>,
+}
+
+/// An interface exposed by a contract through an impl
+pub struct ContractInterface {
+    /// Name of the impl in the contract
+    pub impl_name: String,
+    /// None if the impl is an alias of an impl that couldn't be found
+    pub trait_name: Option<String>,
+    /// None if there is no non generic trait with the name of the implemented trait
+    pub interface: Option<SRC5Interface>,
+    /// The ItemImpl or ItemImplAlias node of the impl
+    pub node: SyntaxNode,
+}
+
+/// The interfaces exposed by a contract module
+pub struct ContractInterfaces {
+    pub name: String,
+    /// Path of the contract module, from the input module
+    pub module_path: Vec<String>,
+    pub interfaces: Vec<ContractInterface>,
+    /// Path of the file defining the contract
+    pub path: PathBuf,
+    /// The ItemModule node of the contract
+    pub node: SyntaxNode,
+}
+
+#[async_trait]
+impl CliCommand for Contracts {
+    // Parse a file listing the interfaces implemented by each contract
+    async fn run(&self) -> Result<()> {
+        let (config, inputs) = load_config_and_inputs(
+            self.cairo_path.as_deref(),
+            self.config.as_deref(),
+            &self.extern_types,
+        )?;
+        let output_format = self.format.or(config.format).unwrap_or_default();
+
+        // Create a new database with the StarkNet plugin
+        let mut db = get_database_with_starknet_plugin();
+
+        let sources = get_cairo_sources(&mut db, &inputs, &config)?;
+        let contracts = get_contracts_interfaces(&db, &sources)?;
+        print_contracts(&contracts, output_format)?;
+
+        Ok(())
+    }
+}
+
+/// Get the interfaces exposed by the contracts defined in the sources. The implemented
+/// traits are resolved among the non generic traits and the `#[starknet::interface]`
+/// traits defined in the sources, and the embedded impls (ex: `impl OwnableImpl =
+/// OwnableComponent::OwnableImpl<ContractState>;`) among the impls, by their name or their
+/// `#[embeddable_as(<NAME>)]` name.
+pub fn get_contracts_interfaces(
+    db: &RootDatabase,
+    sources: &CairoSources,
+) -> Result<Vec<ContractInterfaces>> {
+    let registry = get_type_registry(db, sources);
+    let traits = get_interface_traits_by_path(db, sources);
+    let impls = get_impls(db, sources);
+
+    let mut contracts = Vec::new();
+    for file in sources.files.iter() {
+        for contract in get_cairo_contracts(db, &file.tree) {
+            let mut interfaces = Vec::new();
+            for cairo_impl in contract.impls.iter() {
+                let trait_path = resolve_path(db, file, &cairo_impl.node, &cairo_impl.trait_path);
+                let cairo_trait = find_item(&traits, &trait_path, &cairo_impl.trait_path);
+                interfaces.push(ContractInterface {
+                    impl_name: cairo_impl.name.clone(),
+                    trait_name: cairo_impl.trait_path.last().cloned(),
+                    interface: get_interface(db, &registry, cairo_trait)?,
+                    node: cairo_impl.node.clone(),
+                });
+            }
+            for impl_alias in contract.impl_aliases.iter() {
+                let source_impl = find_impl(db, file, &impls, impl_alias);
+                let cairo_trait = source_impl.and_then(|source_impl| {
+                    find_item(
+                        &traits,
+                        &source_impl.trait_path,
+                        &source_impl.cairo_impl.trait_path,
+                    )
+                });
+                interfaces.push(ContractInterface {
+                    impl_name: impl_alias.name.clone(),
+                    trait_name: source_impl
+                        .and_then(|source_impl| source_impl.cairo_impl.trait_path.last().cloned()),
+                    interface: get_interface(db, &registry, cairo_trait)?,
+                    node: impl_alias.node.clone(),
+                });
+            }
+
+            contracts.push(ContractInterfaces {
+                name: contract.name,
+                module_path: get_module_path(db, file, &contract.node),
+                interfaces,
+                path: file.path.clone(),
+                node: contract.node,
+            });
+        }
+    }
+    Ok(contracts)
+}
+
+/// Get the traits contracts may implement, by path from the input module: the non generic
+/// traits and the `#[starknet::interface]` traits without their `self` parameters
+fn get_interface_traits_by_path(
+    db: &RootDatabase,
+    sources: &CairoSources,
+) -> Vec<(Vec<String>, CairoNonGenericTrait)> {
+    let mut traits = Vec::new();
+    for file in sources.files.iter() {
+        let file_traits = get_non_generic_traits(db, &file.tree)
+            .into_iter()
+            .chain(get_interface_traits(db, &file.tree));
+        for cairo_trait in file_traits {
+            let mut path = get_module_path(db, file, &cairo_trait.node);
+            path.push(cairo_trait.name.clone());
+            traits.push((path, cairo_trait));
+        }
+    }
+    traits
+}
+
+fn get_interface(
+    db: &RootDatabase,
+    registry: &TypeRegistry,
+    cairo_trait: Option<&CairoNonGenericTrait>,
+) -> Result<Option<SRC5Interface>> {
+    cairo_trait
+        .map(|cairo_trait| get_src5_interface(db, cairo_trait, registry))
+        .transpose()
+}
+
+/// An impl defined in the sources
+pub struct SourceImpl {
+    /// Path of the implemented trait, resolved from the module of the impl
+    pub trait_path: Vec<String>,
+    pub cairo_impl: CairoImpl,
+}
+
+/// Get every impl defined in the sources, exposed or not, by path from the input module.
+/// The impls of components are named as they are embedded in contracts.
+pub fn get_impls(db: &RootDatabase, sources: &CairoSources) -> Vec<(Vec<String>, SourceImpl)> {
+    let mut impls = Vec::new();
+    for file in sources.files.iter() {
+        for cairo_impl in get_cairo_impls(db, &file.tree) {
+            let mut path = get_module_path(db, file, &cairo_impl.node);
+            path.push(
+                cairo_impl
+                    .embeddable_as
+                    .clone()
+                    .unwrap_or_else(|| cairo_impl.name.clone()),
+            );
+            let trait_path = resolve_path(db, file, &cairo_impl.node, &cairo_impl.trait_path);
+            impls.push((
+                path,
+                SourceImpl {
+                    trait_path,
+                    cairo_impl,
+                },
+            ));
+        }
+    }
+    impls
+}
+
+/// Find the impl embedded by an impl alias of a contract defined in the file
+pub fn find_impl<'a>(
+    db: &RootDatabase,
+    file: &CairoFile,
+    impls: &'a [(Vec<String>, SourceImpl)],
+    impl_alias: &CairoImplAlias,
+) -> Option<&'a SourceImpl> {
+    let impl_path = resolve_path(db, file, &impl_alias.node, &impl_alias.impl_path);
+    find_item(impls, &impl_path, &impl_alias.impl_path)
+}
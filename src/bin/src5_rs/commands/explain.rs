@@ -0,0 +1,338 @@
+use std::collections::{BTreeSet, HashMap, VecDeque};
+use std::path::PathBuf;
+
+use anyhow::{Ok, Result};
+use async_trait::async_trait;
+use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::SyntaxNode;
+use clap::Parser;
+use src5_rs::interface::{get_src5_interface, SRC5Interface};
+use src5_rs::parser::ast::get_database_with_starknet_plugin;
+use src5_rs::parser::cairo_function::CairoNonGenericFunction;
+use src5_rs::parser::cairo_trait::CairoNonGenericTrait;
+use src5_rs::parser::utils::{find_children, get_path_segments};
+
+use super::parse::{
+    get_cairo_sources, get_file_traits, get_trait_path, get_type_registry, load_config_and_inputs,
+    CairoSources,
+};
+use super::CliCommand;
+use crate::config::Config;
+
+#[derive(Parser, Debug)]
+pub struct Explain {
+    #[clap(help = "File path to the previous version of the Cairo source code")]
+    pub old_cairo_path: String,
+    #[clap(help = "File path to the Cairo source code (defaults to the configured inputs)")]
+    pub cairo_path: Option<String>,
+    #[clap(
+        short,
+        long,
+        help = "Path to the configuration file (defaults to the src5.toml found upward from the input)"
+    )]
+    pub config: Option<String>,
+    #[clap(
+        long = "extern-type",
+        value_name = "TYPE",
+        help = "Declare an opaque type, with its generic parameters if any (ex: Vault<T>)"
+    )]
+    pub extern_types: Vec<String>,
+}
+
+/// The traits of a version of the sources, with the type definitions they depend on
+struct Version {
+    sources: CairoSources,
+    /// The traits and their interfaces, by path from the input module
+    traits: Vec<(String, CairoNonGenericTrait, SRC5Interface, usize)>,
+    /// The struct, enum or type alias resolved for each name, and its file index
+    definitions: HashMap<String, (SyntaxNode, usize)>,
+}
+
+#[async_trait]
+impl CliCommand for Explain {
+    // Parse two versions of a file explaining the changes of the interface ids
+    async fn run(&self) -> Result<()> {
+        let (config, inputs) = load_config_and_inputs(
+            self.cairo_path.as_deref(),
+            self.config.as_deref(),
+            &self.extern_types,
+        )?;
+
+        // Create a new database with the StarkNet plugin
+        let mut db = get_database_with_starknet_plugin();
+
+        let old = get_version(&mut db, &[PathBuf::from(&self.old_cairo_path)], &config)?;
+        let new = get_version(&mut db, &inputs, &config)?;
+
+        let mut changes_count = 0;
+        for (path, new_trait, new_interface, _) in new.traits.iter() {
+            let Some((_, old_trait, old_interface, _)) =
+                old.traits.iter().find(|(old_path, ..)| old_path == path)
+            else {
+                println!("{path}: added (id 0x{:x})", new_interface.id);
+                continue;
+            };
+            if old_interface.id == new_interface.id {
+                continue;
+            }
+            changes_count += 1;
+            println!(
+                "{}: id changed from 0x{:x} to 0x{:x}",
+                path, old_interface.id, new_interface.id
+            );
+
+            for (new_function, new_src5_function) in new_trait
+                .functions
+                .iter()
+                .zip(new_interface.functions.iter())
+            {
+                let Some((old_function, old_src5_function)) = old_trait
+                    .functions
+                    .iter()
+                    .zip(old_interface.functions.iter())
+                    .find(|(old_function, _)| old_function.name == new_function.name)
+                else {
+                    println!("  {}: added", new_src5_function.signature);
+                    continue;
+                };
+                if old_src5_function.signature == new_src5_function.signature {
+                    continue;
+                }
+                println!("  {}:", new_function.name);
+                println!("    - {}", old_src5_function.signature);
+                println!("    + {}", new_src5_function.signature);
+                let causes = get_causes(&db, &old, old_function, &new, new_function);
+                for cause in causes.iter() {
+                    println!("    caused by {cause}");
+                }
+                if causes.is_empty() {
+                    println!(
+                        "    caused by a change outside of the sources (ex: the corelib types)"
+                    );
+                }
+            }
+            for old_src5_function in old_interface.functions.iter() {
+                if !new_interface
+                    .functions
+                    .iter()
+                    .any(|function| function.name == old_src5_function.name)
+                {
+                    println!("  {}: removed", old_src5_function.signature);
+                }
+            }
+        }
+        for (path, _, old_interface, _) in old.traits.iter() {
+            if !new.traits.iter().any(|(new_path, ..)| new_path == path) {
+                println!("{path}: removed (id 0x{:x})", old_interface.id);
+            }
+        }
+
+        if changes_count == 0 {
+            println!("No interface id changed");
+        }
+        Ok(())
+    }
+}
+
+/// Parse a version of the sources, computing the interfaces of its traits
+fn get_version(db: &mut RootDatabase, inputs: &[PathBuf], config: &Config) -> Result<Version> {
+    let sources = get_cairo_sources(db, inputs, config)?;
+    let db = &*db;
+    let registry = get_type_registry(db, &sources);
+
+    let mut traits = Vec::new();
+    let mut definitions = HashMap::new();
+    for (i, file) in sources.files.iter().enumerate() {
+        for cairo_trait in get_file_traits(db, file, config) {
+            let interface = get_src5_interface(db, &cairo_trait, &registry)?;
+            traits.push((
+                get_trait_path(db, file, &cairo_trait),
+                cairo_trait,
+                interface,
+                i,
+            ));
+        }
+        for node in file.tree.descendants(db) {
+            if matches!(
+                node.kind(db),
+                SyntaxKind::ItemStruct | SyntaxKind::ItemEnum | SyntaxKind::ItemTypeAlias
+            ) {
+                // The same precedence as the type registry: the last definition of a name
+                // wins, the structs before the enums before the type aliases
+                let name = get_name_node(db, &node).get_text_without_trivia(db);
+                let overridden = definitions.get(&name).is_none_or(|(defined, _)| {
+                    get_definition_rank(db, defined) >= get_definition_rank(db, &node)
+                });
+                if overridden {
+                    definitions.insert(name, (node, i));
+                }
+            }
+        }
+    }
+    Ok(Version {
+        sources,
+        traits,
+        definitions,
+    })
+}
+
+/// Get the descriptions of the changes causing the signature of a function to change:
+/// the changes of its declaration, and of the definitions of the types it depends on
+fn get_causes(
+    db: &RootDatabase,
+    old: &Version,
+    old_function: &CairoNonGenericFunction,
+    new: &Version,
+    new_function: &CairoNonGenericFunction,
+) -> Vec<String> {
+    let old_location =
+        |node: &SyntaxNode, file_index: usize| old.sources.files[file_index].get_location(db, node);
+    let new_location =
+        |node: &SyntaxNode, file_index: usize| new.sources.files[file_index].get_location(db, node);
+
+    let mut causes = Vec::new();
+    if get_types_text(db, old_function) != get_types_text(db, new_function) {
+        let old_file = get_trait_file(old, old_function);
+        let new_file = get_trait_file(new, new_function);
+        causes.push(format!(
+            "the declaration of {} ({} -> {})",
+            new_function.name,
+            old_location(&old_function.node, old_file),
+            new_location(&new_function.node, new_file)
+        ));
+    }
+
+    let mut names = get_dependencies(db, old, old_function);
+    names.extend(get_dependencies(db, new, new_function));
+    for name in names.iter() {
+        match (old.definitions.get(name), new.definitions.get(name)) {
+            (Some((old_node, old_file)), Some((new_node, new_file))) => {
+                if get_shape(db, old_node) != get_shape(db, new_node) {
+                    causes.push(format!(
+                        "{} {} ({} -> {})",
+                        get_definition_kind(db, new_node),
+                        name,
+                        old_location(&get_name_node(db, old_node), *old_file),
+                        new_location(&get_name_node(db, new_node), *new_file)
+                    ));
+                }
+            }
+            (None, Some((new_node, new_file))) => causes.push(format!(
+                "{} {} added ({})",
+                get_definition_kind(db, new_node),
+                name,
+                new_location(&get_name_node(db, new_node), *new_file)
+            )),
+            (Some((old_node, old_file)), None) => causes.push(format!(
+                "{} {} removed ({})",
+                get_definition_kind(db, old_node),
+                name,
+                old_location(&get_name_node(db, old_node), *old_file)
+            )),
+            (None, None) => {}
+        }
+    }
+    causes
+}
+
+/// Get the index of the file defining the trait of a function
+fn get_trait_file(version: &Version, function: &CairoNonGenericFunction) -> usize {
+    version
+        .traits
+        .iter()
+        .find(|(_, cairo_trait, ..)| {
+            cairo_trait
+                .functions
+                .iter()
+                .any(|trait_function| trait_function.node == function.node)
+        })
+        .map(|(.., file_index)| *file_index)
+        .unwrap_or_default()
+}
+
+/// Get the names of the types defined in the sources that the function depends on,
+/// directly or through other definitions
+fn get_dependencies(
+    db: &RootDatabase,
+    version: &Version,
+    function: &CairoNonGenericFunction,
+) -> BTreeSet<String> {
+    let mut pending: VecDeque<String> = function
+        .inputs_types
+        .iter()
+        .chain(function.return_type.iter())
+        .flat_map(|ty| get_referenced_names(db, ty))
+        .collect();
+    let mut dependencies = BTreeSet::new();
+    while let Some(name) = pending.pop_front() {
+        if let Some((node, _)) = version.definitions.get(&name) {
+            if dependencies.insert(name) {
+                pending.extend(get_referenced_names(db, node));
+            }
+        }
+    }
+    dependencies
+}
+
+/// Get the names of the types referenced in a node (ex: [Array, Call] for `Array<Call>`)
+fn get_referenced_names(db: &RootDatabase, node: &SyntaxNode) -> Vec<String> {
+    node.descendants(db)
+        .filter(|descendant| descendant.kind(db) == SyntaxKind::ExprPath)
+        .filter_map(|expr_path| get_path_segments(db, &expr_path).pop())
+        .collect()
+}
+
+/// Get the part of a definition contributing to the SRC5 types: the generic parameters,
+/// and the types of the members, the variants or the aliased type
+fn get_shape(db: &RootDatabase, node: &SyntaxNode) -> String {
+    let mut shape = find_children(db, node, SyntaxKind::WrappedGenericParamList)
+        .map(|generics| generics.get_text_without_trivia(db))
+        .unwrap_or_default();
+    if node.kind(db) == SyntaxKind::ItemTypeAlias {
+        // The aliased type is the last child before the semicolon
+        if let Some(ty) = node.children(db).rev().nth(1) {
+            shape.push_str(&ty.get_text_without_trivia(db));
+        }
+    } else {
+        let types: Vec<_> = node
+            .descendants(db)
+            .filter(|descendant| descendant.kind(db) == SyntaxKind::TypeClause)
+            .map(|type_clause| type_clause.get_text_without_trivia(db))
+            .collect();
+        shape.push_str(&types.join(","));
+    }
+    shape
+}
+
+/// Get the text of the parameters and return types of a function
+fn get_types_text(db: &RootDatabase, function: &CairoNonGenericFunction) -> Vec<String> {
+    function
+        .inputs_types
+        .iter()
+        .chain(function.return_type.iter())
+        .map(|ty| ty.clone().get_text_without_trivia(db))
+        .collect()
+}
+
+fn get_definition_kind(db: &RootDatabase, node: &SyntaxNode) -> &'static str {
+    match node.kind(db) {
+        SyntaxKind::ItemStruct => "struct",
+        SyntaxKind::ItemEnum => "enum",
+        _ => "type alias",
+    }
+}
+
+/// Get the rank of a definition kind when resolving a name, the lowest first
+fn get_definition_rank(db: &RootDatabase, node: &SyntaxNode) -> usize {
+    match node.kind(db) {
+        SyntaxKind::ItemStruct => 0,
+        SyntaxKind::ItemEnum => 1,
+        _ => 2,
+    }
+}
+
+/// Get the name of an item, reported instead of the item for skipping its attributes
+fn get_name_node(db: &RootDatabase, item: &SyntaxNode) -> SyntaxNode {
+    find_children(db, item, SyntaxKind::TerminalIdentifier).unwrap_or_else(|| item.clone())
+}
@@ -0,0 +1,180 @@
+use anyhow::{bail, Ok, Result};
+use async_trait::async_trait;
+use clap::{Parser, ValueEnum};
+use num_bigint::BigUint;
+use src5_rs::parser::ast::get_database_with_starknet_plugin;
+use src5_rs::selector::get_selector_from_signature;
+
+use super::contracts::{get_contracts_interfaces, ContractInterface, ContractInterfaces};
+use super::parse::{get_cairo_sources, load_config_and_inputs};
+use super::CliCommand;
+use crate::config::Config;
+
+/// Name and signature of the ISRC5 trait, supported by every contract implementing it
+pub const ISRC5_NAME: &str = "ISRC5";
+pub const ISRC5_SIGNATURE: &str = "supports_interface(felt252)->E((),())";
+
+#[derive(ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum GenerationStyle {
+    /// An ISRC5 impl whose supports_interface checks every interface id
+    #[default]
+    Impl,
+    /// The register_interface calls for the contract constructor
+    Constructor,
+}
+
+#[derive(Parser, Debug)]
+pub struct Generate {
+    #[clap(help = "File path to the Cairo source code (defaults to the configured inputs)")]
+    pub cairo_path: Option<String>,
+    #[clap(
+        long,
+        help = "Name of the contract module (defaults to every contract)"
+    )]
+    pub contract: Option<String>,
+    #[clap(
+        short,
+        long,
+        value_enum,
+        default_value_t,
+        help = "Generated code style"
+    )]
+    pub style: GenerationStyle,
+    #[clap(
+        short,
+        long,
+        help = "Path to the configuration file (defaults to the src5.toml found upward from the input)"
+    )]
+    pub config: Option<String>,
+    #[clap(
+        long = "extern-type",
+        value_name = "TYPE",
+        help = "Declare an opaque type, with its generic parameters if any (ex: Vault<T>)"
+    )]
+    pub extern_types: Vec<String>,
+}
+
+#[async_trait]
+impl CliCommand for Generate {
+    // Parse a file generating the SRC5 support code of each contract
+    async fn run(&self) -> Result<()> {
+        let (config, inputs) = load_config_and_inputs(
+            self.cairo_path.as_deref(),
+            self.config.as_deref(),
+            &self.extern_types,
+        )?;
+
+        // Create a new database with the StarkNet plugin
+        let mut db = get_database_with_starknet_plugin();
+
+        let sources = get_cairo_sources(&mut db, &inputs, &config)?;
+        let mut contracts = get_contracts_interfaces(&db, &sources)?;
+        if let Some(contract_name) = &self.contract {
+            contracts.retain(|contract| contract.name == *contract_name);
+            if contracts.is_empty() {
+                bail!("Contract not found: {}", contract_name);
+            }
+        }
+
+        for (i, contract) in contracts.iter().enumerate() {
+            if i > 0 {
+                println!();
+            }
+            print!("{}", get_src5_code(contract, self.style, &config));
+        }
+        Ok(())
+    }
+}
+
+/// Get the Cairo code declaring the ids of the interfaces implemented by the contract,
+/// and supporting them in the given style
+pub fn get_src5_code(
+    contract: &ContractInterfaces,
+    style: GenerationStyle,
+    config: &Config,
+) -> String {
+    let mut code = format!("// Contract: {}\n", contract.name);
+
+    // The ISRC5 interface is always supported
+    let mut ids: Vec<(String, BigUint)> = vec![(
+        ISRC5_NAME.into(),
+        get_selector_from_signature(ISRC5_SIGNATURE),
+    )];
+    for interface in contract.interfaces.iter() {
+        match &interface.interface {
+            Some(interface) => {
+                if !ids.iter().any(|(_, id)| *id == interface.id) {
+                    ids.push((interface.name.clone(), interface.id.clone()));
+                }
+            }
+            None => code.push_str(&format!(
+                "// {}: the interface id can't be computed without a non generic trait\n",
+                interface
+                    .trait_name
+                    .as_deref()
+                    .unwrap_or(&interface.impl_name)
+            )),
+        }
+    }
+
+    // Declare the interface ids
+    for (name, id) in ids.iter() {
+        code.push_str(&format!(
+            "const {}: felt252 = 0x{:x};\n",
+            config.constants.get_constant_name(name),
+            id
+        ));
+    }
+    code.push('\n');
+
+    match style {
+        GenerationStyle::Impl => {
+            // A second impl of ISRC5 wouldn't compile, so only the ids are generated
+            if let Some(existing) = get_src5_impl(contract) {
+                code.push_str(&format!(
+                    "// {}: the contract already implements ISRC5, add the ids to its supports_interface\n",
+                    existing.impl_name
+                ));
+                return code;
+            }
+            code.push_str("#[external(v0)]\n");
+            code.push_str("impl SRC5Impl of ISRC5<ContractState> {\n");
+            code.push_str(
+                "    fn supports_interface(self: @ContractState, interface_id: felt252) -> bool {\n",
+            );
+            for (i, (name, _)) in ids.iter().enumerate() {
+                let operator = if i == 0 {
+                    "        "
+                } else {
+                    "            || "
+                };
+                code.push_str(&format!(
+                    "{}interface_id == {}\n",
+                    operator,
+                    config.constants.get_constant_name(name)
+                ));
+            }
+            code.push_str("    }\n}\n");
+        }
+        GenerationStyle::Constructor => {
+            // The SRC5 implementation already supports the ISRC5 interface
+            code.push_str("// Register the interfaces in the constructor\n");
+            code.push_str("let mut unsafe_state = SRC5::unsafe_new_contract_state();\n");
+            for (name, _) in ids.iter().skip(1) {
+                code.push_str(&format!(
+                    "SRC5::InternalImpl::register_interface(ref unsafe_state, {});\n",
+                    config.constants.get_constant_name(name)
+                ));
+            }
+        }
+    }
+    code
+}
+
+/// Get the impl exposing ISRC5 in the contract if any, given by the implemented trait or
+/// by the impl name for the embedded impls whose trait couldn't be found
+fn get_src5_impl(contract: &ContractInterfaces) -> Option<&ContractInterface> {
+    contract.interfaces.iter().find(|interface| {
+        interface.trait_name.as_deref() == Some(ISRC5_NAME) || interface.impl_name == "SRC5Impl"
+    })
+}
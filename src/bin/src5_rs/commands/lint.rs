@@ -0,0 +1,256 @@
+use std::collections::HashMap;
+
+use anyhow::{bail, Result};
+use async_trait::async_trait;
+use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::SyntaxNode;
+use clap::Parser;
+use num_bigint::BigUint;
+use src5_rs::interface::get_src5_interface;
+use src5_rs::parser::ast::get_database_with_starknet_plugin;
+use src5_rs::parser::cairo_contract::get_cairo_contracts;
+use src5_rs::parser::utils::find_children;
+use src5_rs::src5_type::SRC5Typed;
+
+use super::contracts::{find_impl, get_impls};
+use super::parse::{
+    get_cairo_sources, get_file_traits, get_type_registry, load_config_and_inputs, CairoSources,
+};
+use super::CliCommand;
+use crate::config::{Config, LintLevel};
+
+#[derive(Parser, Debug)]
+pub struct Lint {
+    #[clap(help = "File path to the Cairo source code (defaults to the configured inputs)")]
+    pub cairo_path: Option<String>,
+    #[clap(
+        short,
+        long,
+        help = "Path to the configuration file (defaults to the src5.toml found upward from the input)"
+    )]
+    pub config: Option<String>,
+    #[clap(
+        long = "extern-type",
+        value_name = "TYPE",
+        help = "Declare an opaque type, with its generic parameters if any (ex: Vault<T>)"
+    )]
+    pub extern_types: Vec<String>,
+}
+
+struct LintDiagnostic {
+    /// Location in the source code, as `path:line:column`
+    location: String,
+    level: LintLevel,
+    rule: &'static str,
+    message: String,
+}
+
+#[async_trait]
+impl CliCommand for Lint {
+    // Parse a file reporting the SRC5 design pitfalls of the traits
+    async fn run(&self) -> Result<()> {
+        let (config, inputs) = load_config_and_inputs(
+            self.cairo_path.as_deref(),
+            self.config.as_deref(),
+            &self.extern_types,
+        )?;
+
+        // Create a new database with the StarkNet plugin
+        let mut db = get_database_with_starknet_plugin();
+
+        let sources = get_cairo_sources(&mut db, &inputs, &config)?;
+        let diagnostics = lint(&db, &sources, &config);
+        for diagnostic in diagnostics.iter() {
+            let level = match diagnostic.level {
+                LintLevel::Error => "error",
+                _ => "warning",
+            };
+            println!(
+                "{}: {}[{}]: {}",
+                diagnostic.location, level, diagnostic.rule, diagnostic.message
+            );
+        }
+
+        let errors_count = diagnostics
+            .iter()
+            .filter(|diagnostic| diagnostic.level == LintLevel::Error)
+            .count();
+        if errors_count > 0 {
+            bail!(
+                "Found {} errors and {} warnings",
+                errors_count,
+                diagnostics.len() - errors_count
+            );
+        }
+        Ok(())
+    }
+}
+
+/// Run the enabled lint rules over the traits and contracts of the sources
+fn lint(db: &RootDatabase, sources: &CairoSources, config: &Config) -> Vec<LintDiagnostic> {
+    let rules = &config.lint;
+    let registry = get_type_registry(db, sources);
+    let mut diagnostics = Vec::new();
+    let mut report = |level: LintLevel, rule, location, message| {
+        if level != LintLevel::Off {
+            diagnostics.push(LintDiagnostic {
+                location,
+                level,
+                rule,
+                message,
+            });
+        }
+    };
+
+    // Traits already found by interface id
+    let mut ids: HashMap<BigUint, String> = HashMap::new();
+    for file in sources.files.iter() {
+        for cairo_trait in get_file_traits(db, file, config) {
+            let trait_location = file.get_location(db, &get_name_node(db, &cairo_trait.node));
+            if cairo_trait.functions.is_empty() {
+                report(
+                    rules.empty_trait,
+                    "empty_trait",
+                    trait_location,
+                    format!(
+                        "{} has no functions, so its interface id is 0",
+                        cairo_trait.name
+                    ),
+                );
+                continue;
+            }
+
+            let mut resolved = true;
+            for function in cairo_trait.functions.iter() {
+                for ty in function
+                    .inputs_types
+                    .iter()
+                    .chain(function.return_type.iter())
+                {
+                    let type_text = get_type_text(db, ty);
+                    match ty.get_src5_type(db, &registry) {
+                        Err(error) => {
+                            resolved = false;
+                            report(
+                                rules.unresolved_type,
+                                "unresolved_type",
+                                file.get_location(db, ty),
+                                format!("`{}` in {}: {}", type_text, function.name, error),
+                            );
+                        }
+                        Ok(src5_type) => {
+                            let depth = get_type_depth(&src5_type);
+                            if depth > rules.max_type_depth {
+                                report(
+                                    rules.deep_type,
+                                    "deep_type",
+                                    file.get_location(db, ty),
+                                    format!(
+                                        "`{}` in {} is nested {} levels deep (max {}), so \
+                                         changes in the nested types change the interface id",
+                                        type_text, function.name, depth, rules.max_type_depth
+                                    ),
+                                );
+                            }
+                        }
+                    }
+                }
+            }
+
+            if resolved {
+                let interface = get_src5_interface(db, &cairo_trait, &registry).unwrap();
+                match ids.get(&interface.id) {
+                    Some(name) if *name != cairo_trait.name => report(
+                        rules.duplicate_id,
+                        "duplicate_id",
+                        trait_location,
+                        format!(
+                            "{} has the same interface id as {} (0x{:x})",
+                            cairo_trait.name, name, interface.id
+                        ),
+                    ),
+                    Some(_) => {}
+                    None => {
+                        ids.insert(interface.id, cairo_trait.name.clone());
+                    }
+                }
+            }
+        }
+    }
+
+    // The entry point selectors only depend on the function names
+    let impls = get_impls(db, sources);
+    for file in sources.files.iter() {
+        for contract in get_cairo_contracts(db, &file.tree) {
+            let mut exposed = contract
+                .impls
+                .iter()
+                .map(|cairo_impl| (cairo_impl.name.clone(), cairo_impl, &cairo_impl.node))
+                .collect::<Vec<_>>();
+            for impl_alias in contract.impl_aliases.iter() {
+                if let Some(source_impl) = find_impl(db, file, &impls, impl_alias) {
+                    exposed.push((
+                        impl_alias.name.clone(),
+                        &source_impl.cairo_impl,
+                        &impl_alias.node,
+                    ));
+                }
+            }
+
+            // Impls already found by function name
+            let mut functions: HashMap<&str, &str> = HashMap::new();
+            for (impl_name, cairo_impl, node) in exposed.iter() {
+                for function in cairo_impl.functions.iter() {
+                    match functions.get(function.as_str()) {
+                        Some(other_impl) => report(
+                            rules.selector_clash,
+                            "selector_clash",
+                            file.get_location(db, &get_name_node(db, node)),
+                            format!(
+                                "{} is exposed by {} and {} in {}, with the same selector",
+                                function, other_impl, impl_name, contract.name
+                            ),
+                        ),
+                        None => {
+                            functions.insert(function, impl_name);
+                        }
+                    }
+                }
+            }
+        }
+    }
+    diagnostics
+}
+
+/// Get the name of an item, reported instead of the item for skipping its attributes
+fn get_name_node(db: &RootDatabase, item: &SyntaxNode) -> SyntaxNode {
+    find_children(db, item, SyntaxKind::TerminalIdentifier).unwrap()
+}
+
+/// Get the text of a TypeClause or ReturnTypeClause, without the `:` or `->`
+fn get_type_text(db: &RootDatabase, type_clause: &SyntaxNode) -> String {
+    type_clause
+        .children(db)
+        .last()
+        .map(|ty| ty.get_text_without_trivia(db))
+        .unwrap_or_default()
+}
+
+/// Get the maximum nesting depth of generic arguments and tuples (including structs
+/// and enums) in an SRC5 type
+fn get_type_depth(src5_type: &str) -> usize {
+    let mut depth = 0;
+    let mut max_depth = 0;
+    for c in src5_type.chars() {
+        match c {
+            '(' | '<' => {
+                depth += 1;
+                max_depth = max_depth.max(depth);
+            }
+            ')' | '>' => depth -= 1,
+            _ => {}
+        }
+    }
+    max_depth
+}
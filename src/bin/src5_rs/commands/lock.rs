@@ -0,0 +1,102 @@
+use std::path::PathBuf;
+
+use anyhow::{Ok, Result};
+use async_trait::async_trait;
+use cairo_lang_compiler::db::RootDatabase;
+use clap::Parser;
+use src5_rs::interface::get_src5_interface;
+use src5_rs::parser::ast::get_database_with_starknet_plugin;
+
+use super::parse::{
+    get_cairo_sources, get_file_traits, get_trait_path, get_type_registry, load_config_and_inputs,
+    CairoSources,
+};
+use super::CliCommand;
+use crate::config::Config;
+use crate::lockfile::{LockedInterface, Lockfile, LOCK_FILE_NAME};
+
+#[derive(Parser, Debug)]
+pub struct Lock {
+    #[clap(help = "File path to the Cairo source code (defaults to the configured inputs)")]
+    pub cairo_path: Option<String>,
+    #[clap(
+        long,
+        help = "Path to the lockfile (defaults to the src5.lock next to the configuration file)"
+    )]
+    pub lockfile: Option<String>,
+    #[clap(
+        short,
+        long,
+        help = "Path to the configuration file (defaults to the src5.toml found upward from the input)"
+    )]
+    pub config: Option<String>,
+    #[clap(
+        long = "extern-type",
+        value_name = "TYPE",
+        help = "Declare an opaque type, with its generic parameters if any (ex: Vault<T>)"
+    )]
+    pub extern_types: Vec<String>,
+}
+
+#[async_trait]
+impl CliCommand for Lock {
+    // Parse a file recording the interface id of each trait in the lockfile
+    async fn run(&self) -> Result<()> {
+        let (config, inputs) = load_config_and_inputs(
+            self.cairo_path.as_deref(),
+            self.config.as_deref(),
+            &self.extern_types,
+        )?;
+
+        // Create a new database with the StarkNet plugin
+        let mut db = get_database_with_starknet_plugin();
+
+        let sources = get_cairo_sources(&mut db, &inputs, &config)?;
+        let lockfile = Lockfile {
+            interfaces: get_locked_interfaces(&db, &sources, &config)?,
+        };
+        let lockfile_path = get_lockfile_path(self.lockfile.as_deref(), &config);
+        lockfile.save(&lockfile_path)?;
+        println!(
+            "Locked {} interfaces in {}",
+            lockfile.interfaces.len(),
+            lockfile_path.display()
+        );
+        Ok(())
+    }
+}
+
+/// Get the lockfile path, the passed one or the one next to the configuration file
+pub fn get_lockfile_path(lockfile_path: Option<&str>, config: &Config) -> PathBuf {
+    match lockfile_path {
+        Some(lockfile_path) => PathBuf::from(lockfile_path),
+        None => config.root_dir.join(LOCK_FILE_NAME),
+    }
+}
+
+/// Get the interfaces of the non generic traits defined in the sources, identified by
+/// their path from the input module
+pub fn get_locked_interfaces(
+    db: &RootDatabase,
+    sources: &CairoSources,
+    config: &Config,
+) -> Result<Vec<LockedInterface>> {
+    let registry = get_type_registry(db, sources);
+
+    let mut interfaces = Vec::new();
+    for file in sources.files.iter() {
+        for cairo_trait in get_file_traits(db, file, config) {
+            let interface = get_src5_interface(db, &cairo_trait, &registry)?;
+            interfaces.push(LockedInterface {
+                path: get_trait_path(db, file, &cairo_trait),
+                id: format!("0x{:x}", interface.id),
+                signatures: interface
+                    .functions
+                    .into_iter()
+                    .map(|function| function.signature)
+                    .collect(),
+            });
+        }
+    }
+    Ok(interfaces)
+}
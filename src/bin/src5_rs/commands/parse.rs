@@ -1,60 +1,366 @@
-use anyhow::{Ok, Result};
+mod parallel;
+mod watch;
+
+use std::collections::{HashSet, VecDeque};
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Ok, Result};
 use async_trait::async_trait;
+use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_syntax::node::SyntaxNode;
 use clap::Parser;
-use num_bigint::BigUint;
-use prettytable::{format, Table};
+use src5_rs::interface::{get_src5_interface, SRC5Interface};
 use src5_rs::parser::ast::get_database_with_starknet_plugin;
-use src5_rs::parser::ast::get_syntax_tree;
-use src5_rs::parser::cairo_enum::get_cairo_enums;
-use src5_rs::parser::cairo_struct::get_cairo_structs;
-use src5_rs::parser::cairo_trait::get_non_generic_traits;
-use src5_rs::selector::get_selector_from_signature;
+use src5_rs::parser::ast::{get_file_syntax_tree, get_syntax_tree};
+use src5_rs::parser::cairo_module::{get_enclosing_modules, get_external_modules, get_module_uses};
+use src5_rs::parser::cairo_trait::{get_non_generic_traits, CairoNonGenericTrait};
+use src5_rs::parser::utils::get_text_position;
+use src5_rs::type_registry::TypeRegistry;
 
 use super::CliCommand;
+use crate::config::{Config, OutputFormat, CONFIG_FILE_NAME};
+use crate::output::{get_traits_docs, print_interfaces};
 
-#[derive(Parser, Debug)]
+#[derive(Parser, Debug, Default)]
 pub struct Parse {
-    #[clap(help = "File path to the Cairo source code")]
-    pub cairo_path: String,
+    #[clap(help = "File path to the Cairo source code (defaults to the configured inputs)")]
+    pub cairo_path: Option<String>,
+    #[clap(
+        short,
+        long,
+        help = "Watch the file and its modules, recomputing the interface ids on changes"
+    )]
+    pub watch: bool,
+    #[clap(
+        short,
+        long,
+        value_enum,
+        help = "Output format (defaults to the configured one)"
+    )]
+    pub format: Option<OutputFormat>,
+    #[clap(
+        short,
+        long,
+        help = "Path to the configuration file (defaults to the src5.toml found upward from the input)"
+    )]
+    pub config: Option<String>,
+    #[clap(
+        long = "extern-type",
+        value_name = "TYPE",
+        help = "Declare an opaque type, with its generic parameters if any (ex: Vault<T>)"
+    )]
+    pub extern_types: Vec<String>,
+    #[clap(
+        short,
+        long,
+        help = "Number of threads processing the files (defaults to the available parallelism)"
+    )]
+    pub jobs: Option<usize>,
+}
+
+/// A parsed Cairo file
+pub struct CairoFile {
+    pub path: PathBuf,
+    /// Path of the module defined by the file, from the input module
+    pub module_path: Vec<String>,
+    pub tree: SyntaxNode,
+}
+
+impl CairoFile {
+    /// Get the location of a node of the file, as `path:line:column`
+    pub fn get_location(&self, db: &RootDatabase, node: &SyntaxNode) -> String {
+        let content = self.tree.get_text(db);
+        let position = get_text_position(&content, node.span_without_trivia(db).start);
+        format!(
+            "{}:{}:{}",
+            self.path.display(),
+            position.line + 1,
+            position.col + 1
+        )
+    }
+}
+
+/// The parsed Cairo files of the inputs and the modules they reference
+pub struct CairoSources {
+    pub files: Vec<CairoFile>,
+    /// Type definitions and extern types declarations from the configuration
+    pub extra_types: Option<SyntaxNode>,
 }
 
 #[async_trait]
 impl CliCommand for Parse {
     // Parse a file generating interface signatures for each trait
     async fn run(&self) -> Result<()> {
-        // Read Cairo file content
-        let cairo_code = std::fs::read_to_string(&self.cairo_path)?;
+        let (config, inputs) = load_config_and_inputs(
+            self.cairo_path.as_deref(),
+            self.config.as_deref(),
+            &self.extern_types,
+        )?;
+        let output_format = self.format.or(config.format).unwrap_or_default();
+
+        if self.watch {
+            // The database can't be shared across threads, so it is owned by the watcher
+            return tokio::task::spawn_blocking(move || {
+                watch::watch(&inputs, &config, output_format)
+            })
+            .await?;
+        }
+
         // Create a new database with the StarkNet plugin
-        let db = get_database_with_starknet_plugin();
-        // Get the syntax tree
-        let tree = get_syntax_tree(&db, cairo_code);
-
-        let traits = get_non_generic_traits(&db, &tree);
-        let cairo_structs = get_cairo_structs(&db, &tree);
-        let cairo_enums = get_cairo_enums(&db, &tree);
-
-        println!();
-        let mut trait_table = Table::new();
-        trait_table.set_format(*format::consts::FORMAT_NO_LINESEP_WITH_TITLE);
-        trait_table
-            .set_titles(row![bFg->"SRC5 Function Signature:", bFg->"Extended Function Selector:"]);
-
-        for (i, cairo_trait) in traits.iter().enumerate() {
-            if i > 0 {
-                trait_table.add_empty_row();
+        let mut db = get_database_with_starknet_plugin();
+
+        // Get the syntax trees of the inputs and their modules
+        let sources = get_cairo_sources(&mut db, &inputs, &config)?;
+        let jobs = match self.jobs {
+            Some(jobs) => jobs,
+            None => std::thread::available_parallelism().map_or(1, usize::from),
+        };
+        let interfaces = if jobs > 1 && sources.files.len() > 1 {
+            parallel::get_interfaces_parallel(&db, &sources, &config, jobs)?
+        } else {
+            get_interfaces(&db, &sources, &config)?
+        };
+        let docs = (output_format == OutputFormat::Markdown)
+            .then(|| get_traits_docs(&db, &get_traits(&db, &sources, &config)));
+        print_interfaces(&interfaces, docs.as_deref(), output_format, &config)?;
+
+        Ok(())
+    }
+}
+
+/// Load the configuration, passed or found upward from the input, and get the input files
+/// (the passed one or the configured ones), declaring the extern types passed
+pub fn load_config_and_inputs(
+    cairo_path: Option<&str>,
+    config_path: Option<&str>,
+    extern_types: &[String],
+) -> Result<(Config, Vec<PathBuf>)> {
+    let mut config = match (config_path, cairo_path) {
+        (Some(config_path), _) => Config::load(Path::new(config_path))?,
+        (None, Some(cairo_path)) => Config::discover(
+            &Path::new(cairo_path)
+                .parent()
+                .map(Path::to_path_buf)
+                .unwrap_or_default(),
+        )?,
+        (None, None) => Config::discover(&std::env::current_dir()?)?,
+    };
+    config.extern_types.extend(extern_types.iter().cloned());
+
+    let inputs = match cairo_path {
+        Some(cairo_path) => vec![PathBuf::from(cairo_path)],
+        None => config.get_inputs(),
+    };
+    if inputs.is_empty() {
+        bail!(
+            "No input file: pass a file path, or set the inputs in {}",
+            CONFIG_FILE_NAME
+        );
+    }
+    Ok((config, inputs))
+}
+
+/// Parse the input files, the files of the modules they reference (transitively), and
+/// the extra types from the configuration. Each file keeps the same id in the database
+/// when parsed again.
+pub fn get_cairo_sources(
+    db: &mut RootDatabase,
+    inputs: &[PathBuf],
+    config: &Config,
+) -> Result<CairoSources> {
+    let mut files = Vec::new();
+    let mut visited = HashSet::new();
+    // The input files modules are resolved from their directory, while the modules of
+    // other files are resolved from a directory named after the file
+    let mut pending: VecDeque<_> = inputs.iter().map(|input| (input.clone(), vec![])).collect();
+    while let Some((path, module_path)) = pending.pop_front() {
+        if !visited.insert(path.clone()) {
+            continue;
+        }
+        let cairo_code = std::fs::read_to_string(&path)?;
+        let tree = get_file_syntax_tree(db, &path, cairo_code);
+
+        let mut modules_dir = path.parent().map(Path::to_path_buf).unwrap_or_default();
+        if !module_path.is_empty() {
+            modules_dir.push(path.file_stem().unwrap_or_default());
+        }
+        for external_module in get_external_modules(db, &tree) {
+            let mut module_file = modules_dir.clone();
+            module_file.extend(&external_module);
+            module_file.set_extension("cairo");
+            // Modules without a file (ex: defined by plugins) are ignored
+            if module_file.is_file() {
+                let mut file_module_path = module_path.clone();
+                file_module_path.extend(external_module);
+                pending.push_back((module_file, file_module_path));
             }
-            let mut interface_id = BigUint::from(0u8);
-            trait_table.add_row(row![bFg->cairo_trait.name]);
-            for function in &cairo_trait.functions {
-                let signature = function.get_efs_signature(&db, &cairo_structs, &cairo_enums)?;
-                let selector = get_selector_from_signature(&signature);
-                interface_id ^= selector.clone();
-                trait_table.add_row(row![signature, format!("0x{:x}", selector)]);
+        }
+        files.push(CairoFile {
+            path,
+            module_path,
+            tree,
+        });
+    }
+
+    // Declare the configured extern types along with the extra types
+    let mut extra_types = config.extra_types.clone().unwrap_or_default();
+    for extern_type in config.extern_types.iter() {
+        extra_types.push_str(&format!("\nextern type {extern_type};"));
+    }
+    let extra_types = (!extra_types.is_empty()).then(|| get_syntax_tree(db, extra_types));
+    Ok(CairoSources { files, extra_types })
+}
+
+/// Get the registry with the corelib types and the types defined in the sources
+pub fn get_type_registry(db: &RootDatabase, sources: &CairoSources) -> TypeRegistry {
+    let mut registry = TypeRegistry::with_corelib(db);
+    let trees = sources.files.iter().map(|file| &file.tree);
+    for tree in trees.chain(sources.extra_types.iter()) {
+        registry.register_syntax_tree(db, tree);
+    }
+    registry
+}
+
+/// Get the non generic traits defined in the sources, without the excluded ones
+pub fn get_traits(
+    db: &RootDatabase,
+    sources: &CairoSources,
+    config: &Config,
+) -> Vec<CairoNonGenericTrait> {
+    sources
+        .files
+        .iter()
+        .flat_map(|file| get_file_traits(db, file, config))
+        .collect()
+}
+
+/// Get the non generic traits defined in a file, without the excluded ones
+pub fn get_file_traits(
+    db: &RootDatabase,
+    file: &CairoFile,
+    config: &Config,
+) -> Vec<CairoNonGenericTrait> {
+    let mut traits = Vec::new();
+    for cairo_trait in get_non_generic_traits(db, &file.tree) {
+        let mut module_path = file.module_path.clone();
+        module_path.extend(get_enclosing_modules(db, &cairo_trait.node));
+        if !config.is_excluded(&module_path, &cairo_trait.name) {
+            traits.push(cairo_trait);
+        }
+    }
+    traits
+}
+
+/// Get the path of a trait defined in a file, from the input module
+/// (ex: `account::interface::IAccount`)
+pub fn get_trait_path(
+    db: &RootDatabase,
+    file: &CairoFile,
+    cairo_trait: &CairoNonGenericTrait,
+) -> String {
+    let mut path = get_module_path(db, file, &cairo_trait.node);
+    path.push(cairo_trait.name.clone());
+    path.join("::")
+}
+
+/// Get the path of the module enclosing a node of a file, from the input module
+pub fn get_module_path(db: &RootDatabase, file: &CairoFile, node: &SyntaxNode) -> Vec<String> {
+    let mut module_path = file.module_path.clone();
+    module_path.extend(get_enclosing_modules(db, node));
+    module_path
+}
+
+/// Resolve a path used by a node of a file into a path from the input module, following
+/// `crate`, `self`, `super` and the `use` items of the enclosing module
+pub fn resolve_path(
+    db: &RootDatabase,
+    file: &CairoFile,
+    node: &SyntaxNode,
+    path: &[String],
+) -> Vec<String> {
+    let module_path = get_module_path(db, file, node);
+    // A name imported by a use item, whose path is resolved from the same module
+    if let Some((first, rest)) = path.split_first() {
+        let uses = get_module_uses(db, node);
+        if let Some((_, mut use_path)) = uses.into_iter().find(|(name, _)| name == first) {
+            use_path.extend(rest.iter().cloned());
+            return resolve_path_segments(module_path, &use_path);
+        }
+    }
+    resolve_path_segments(module_path, path)
+}
+
+fn resolve_path_segments(mut resolved: Vec<String>, mut segments: &[String]) -> Vec<String> {
+    if segments.first().map(String::as_str) == Some("crate") {
+        resolved.clear();
+        segments = &segments[1..];
+    }
+    while let Some((first, rest)) = segments.split_first() {
+        match first.as_str() {
+            "super" => {
+                resolved.pop();
             }
-            trait_table.add_row(row![bFg->format!("Id: 0x{:x}", interface_id)]);
+            "self" => {}
+            _ => break,
         }
-        trait_table.printstd();
+        segments = rest;
+    }
+    resolved.extend(segments.iter().cloned());
+    resolved
+}
 
-        Ok(())
+/// Find the item of a resolved path among the items of the sources, given by path.
+/// Paths that can't be resolved (ex: through a dependency) fall back to the items named
+/// as the path ends, the closest to the resolved path winning.
+pub fn find_item<'a, T>(
+    items: &'a [(Vec<String>, T)],
+    resolved_path: &[String],
+    path: &[String],
+) -> Option<&'a T> {
+    if let Some((_, item)) = items
+        .iter()
+        .find(|(item_path, _)| item_path == resolved_path)
+    {
+        return Some(item);
+    }
+    let suffix: Vec<_> = path
+        .iter()
+        .filter(|segment| !["crate", "self", "super"].contains(&segment.as_str()))
+        .collect();
+    if suffix.is_empty() {
+        return None;
+    }
+    items
+        .iter()
+        .filter(|(item_path, _)| {
+            item_path.len() >= suffix.len()
+                && item_path[item_path.len() - suffix.len()..]
+                    .iter()
+                    .eq(suffix.iter().copied())
+        })
+        // The first item wins among the equally close ones
+        .min_by_key(|(item_path, _)| {
+            let common_length = item_path
+                .iter()
+                .zip(resolved_path.iter())
+                .take_while(|(segment, other)| segment == other)
+                .count();
+            std::cmp::Reverse(common_length)
+        })
+        .map(|(_, item)| item)
+}
+
+/// Get the SRC5 interfaces of the non generic traits defined in the sources
+pub fn get_interfaces(
+    db: &RootDatabase,
+    sources: &CairoSources,
+    config: &Config,
+) -> Result<Vec<SRC5Interface>> {
+    let registry = get_type_registry(db, sources);
+
+    let mut interfaces = Vec::new();
+    for cairo_trait in get_traits(db, sources, config) {
+        interfaces.push(get_src5_interface(db, &cairo_trait, &registry)?);
     }
+    Ok(interfaces)
 }
@@ -0,0 +1,56 @@
+// Module for computing the interfaces of many files in parallel
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+
+use anyhow::Result;
+use cairo_lang_compiler::db::RootDatabase;
+use src5_rs::interface::{get_src5_interface, SRC5Interface};
+
+use super::{get_file_traits, get_type_registry, CairoSources};
+use crate::config::Config;
+
+/// Get the SRC5 interfaces of the non generic traits defined in the sources, processing
+/// the files in parallel. The interfaces (or the first error) are the same, and in the
+/// same order, as the ones computed sequentially.
+pub fn get_interfaces_parallel(
+    db: &RootDatabase,
+    sources: &CairoSources,
+    config: &Config,
+    jobs: usize,
+) -> Result<Vec<SRC5Interface>> {
+    // The database can't be shared across threads, so each worker reads the parsed
+    // sources from its own snapshot of it, with its own copy of the registry
+    let registry = get_type_registry(db, sources);
+
+    let next_file = &AtomicUsize::new(0);
+    let results = &Mutex::new(Vec::with_capacity(sources.files.len()));
+    std::thread::scope(|scope| {
+        for _ in 0..jobs.min(sources.files.len()) {
+            let db = db.snapshot();
+            let registry = registry.clone();
+            scope.spawn(move || {
+                // Take the next pending file until there are none left
+                loop {
+                    let index = next_file.fetch_add(1, Ordering::Relaxed);
+                    let Some(file) = sources.files.get(index) else {
+                        break;
+                    };
+                    let interfaces: Result<Vec<_>> = get_file_traits(&db, file, config)
+                        .iter()
+                        .map(|cairo_trait| get_src5_interface(&db, cairo_trait, &registry))
+                        .collect();
+                    results.lock().unwrap().push((index, interfaces));
+                }
+            });
+        }
+    });
+
+    // Merge the results following the files order
+    let mut results = results.lock().unwrap();
+    results.sort_by_key(|(index, _)| *index);
+    let mut interfaces = Vec::new();
+    for (_, file_interfaces) in results.drain(..) {
+        interfaces.extend(file_interfaces?);
+    }
+    Ok(interfaces)
+}
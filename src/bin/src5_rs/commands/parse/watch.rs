@@ -0,0 +1,385 @@
+// Module for recomputing the interface ids when the Cairo files change
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use anyhow::Result;
+use cairo_lang_compiler::db::RootDatabase;
+use src5_rs::interface::{get_src5_interface, SRC5Interface};
+use src5_rs::parser::ast::get_database_with_starknet_plugin;
+use src5_rs::parser::cairo_trait::CairoNonGenericTrait;
+
+use src5_rs::type_registry::TypeRegistry;
+
+use super::{
+    get_cairo_sources, get_file_traits, get_trait_path, get_traits, get_type_registry, CairoSources,
+};
+use crate::config::{Config, OutputFormat};
+use crate::output::{get_traits_docs, print_interfaces};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Interfaces computed in previous iterations, keyed by the text of their traits and the
+/// definitions of the types they depend on
+#[derive(Default)]
+struct InterfacesCache {
+    interfaces: HashMap<String, SRC5Interface>,
+    /// Paths of the traits recomputed in the last call
+    #[cfg(test)]
+    recomputed: Vec<String>,
+}
+
+impl InterfacesCache {
+    /// Get the interfaces of the traits in the sources by trait path, recomputing only the
+    /// traits that changed since the last call
+    fn get_interfaces(
+        &mut self,
+        db: &RootDatabase,
+        sources: &CairoSources,
+        config: &Config,
+    ) -> Result<Vec<(String, SRC5Interface)>> {
+        let registry = get_type_registry(db, sources);
+        let definitions = get_type_definitions(db, &registry);
+
+        #[cfg(test)]
+        self.recomputed.clear();
+        let mut interfaces = HashMap::new();
+        let mut result = Vec::new();
+        for file in sources.files.iter() {
+            for cairo_trait in get_file_traits(db, file, config) {
+                let trait_fingerprint = get_trait_fingerprint(db, &cairo_trait, &definitions);
+                let trait_path = get_trait_path(db, file, &cairo_trait);
+                let interface = match self.interfaces.remove(&trait_fingerprint) {
+                    Some(interface) => interface,
+                    None => {
+                        #[cfg(test)]
+                        self.recomputed.push(trait_path.clone());
+                        get_src5_interface(db, &cairo_trait, &registry)?
+                    }
+                };
+                result.push((trait_path, interface.clone()));
+                interfaces.insert(trait_fingerprint, interface);
+            }
+        }
+        self.interfaces = interfaces;
+        Ok(result)
+    }
+}
+
+/// Print the interfaces of the inputs and their modules, and print them again each
+/// time any of the files changes, along with the ids that changed
+pub fn watch(inputs: &[PathBuf], config: &Config, output_format: OutputFormat) -> Result<()> {
+    // Create a new database with the StarkNet plugin, reused across iterations. The files
+    // keep their ids, so only their latest content is kept in the database.
+    let mut db = get_database_with_starknet_plugin();
+    let mut cache = InterfacesCache::default();
+    let mut previous_ids: Option<HashMap<String, String>> = None;
+    let mut watched_files = get_modification_times(inputs.iter().map(PathBuf::as_path));
+
+    loop {
+        let result = get_cairo_sources(&mut db, inputs, config).and_then(|sources| {
+            let db = &db;
+            let paths = sources.files.iter().map(|file| file.path.as_path());
+            watched_files = get_modification_times(paths);
+            let (paths, interfaces): (Vec<_>, Vec<_>) = cache
+                .get_interfaces(db, &sources, config)?
+                .into_iter()
+                .unzip();
+            let docs = (output_format == OutputFormat::Markdown)
+                .then(|| get_traits_docs(db, &get_traits(db, &sources, config)));
+            print_interfaces(&interfaces, docs.as_deref(), output_format, config)?;
+            Ok((paths, interfaces))
+        });
+        match result {
+            Ok((paths, interfaces)) => {
+                // Traits are identified by path, since many modules may define the same name
+                let ids: HashMap<String, String> = paths
+                    .iter()
+                    .zip(interfaces.iter())
+                    .map(|(path, interface)| (path.clone(), format!("0x{:x}", interface.id)))
+                    .collect();
+                if let Some(previous_ids) = &previous_ids {
+                    print_changed_ids(previous_ids, &ids, &paths);
+                }
+                previous_ids = Some(ids);
+            }
+            Err(error) => eprintln!("\nError: {error}"),
+        }
+        eprintln!("\nWatching {} file(s) for changes...", watched_files.len());
+
+        // Wait until any of the watched files changes
+        while get_modification_times(watched_files.keys().map(PathBuf::as_path)) == watched_files {
+            std::thread::sleep(POLL_INTERVAL);
+        }
+    }
+}
+
+fn get_modification_times<'a>(
+    paths: impl Iterator<Item = &'a Path>,
+) -> HashMap<PathBuf, Option<SystemTime>> {
+    paths
+        .map(|path| {
+            let modified = std::fs::metadata(path).and_then(|m| m.modified()).ok();
+            (path.to_path_buf(), modified)
+        })
+        .collect()
+}
+
+/// Print the ids that changed, keyed by trait path, following the order of the paths
+fn print_changed_ids(
+    previous_ids: &HashMap<String, String>,
+    ids: &HashMap<String, String>,
+    paths: &[String],
+) {
+    let changes = get_changed_ids(previous_ids, ids, paths);
+    if changes.is_empty() {
+        eprintln!("\nNo interface ids changed");
+    } else {
+        eprintln!("\nChanged interface ids:");
+        for change in changes {
+            eprintln!("{change}");
+        }
+    }
+}
+
+/// Get a line for each id that changed, was added or was removed, with removed ids last
+fn get_changed_ids(
+    previous_ids: &HashMap<String, String>,
+    ids: &HashMap<String, String>,
+    paths: &[String],
+) -> Vec<String> {
+    let mut changes = Vec::new();
+    for path in paths.iter() {
+        let id = &ids[path];
+        match previous_ids.get(path) {
+            Some(previous_id) if previous_id != id => {
+                changes.push(format!("  {}: {} -> {}", path, previous_id, id));
+            }
+            None => changes.push(format!("  {}: added ({})", path, id)),
+            _ => {}
+        }
+    }
+    let mut removed: Vec<_> = previous_ids
+        .iter()
+        .filter(|(path, _)| !ids.contains_key(*path))
+        .collect();
+    removed.sort();
+    for (path, previous_id) in removed {
+        changes.push(format!("  {}: removed ({})", path, previous_id));
+    }
+    changes
+}
+
+/// Get the text of the trait functions, followed by the definitions of the types they
+/// depend on, so the fingerprint changes only if the interface may change
+fn get_trait_fingerprint(
+    db: &RootDatabase,
+    cairo_trait: &CairoNonGenericTrait,
+    definitions: &HashMap<String, Vec<String>>,
+) -> String {
+    let mut fingerprint = cairo_trait.name.clone();
+    for function in cairo_trait.functions.iter() {
+        fingerprint.push_str(&format!("|{}(", function.name));
+        for input in function.inputs_types.iter() {
+            fingerprint.push_str(&input.clone().get_text_without_trivia(db));
+            fingerprint.push(',');
+        }
+        fingerprint.push(')');
+        if let Some(return_type) = &function.return_type {
+            fingerprint.push_str(&return_type.clone().get_text_without_trivia(db));
+        }
+    }
+
+    // Follow the names used in the types, and in the definitions found for them. Names are
+    // not resolved, so every definition with a used name is included.
+    let mut dependencies = Vec::new();
+    let mut visited = HashSet::new();
+    let mut pending: Vec<String> = get_names(&fingerprint).collect();
+    while let Some(name) = pending.pop() {
+        if !visited.insert(name.clone()) {
+            continue;
+        }
+        for definition in definitions.get(&name).into_iter().flatten() {
+            pending.extend(get_names(definition));
+            dependencies.push(definition.as_str());
+        }
+    }
+    dependencies.sort();
+    fingerprint.push_str("||");
+    fingerprint.push_str(&dependencies.join("|"));
+    fingerprint
+}
+
+/// Get the identifiers in the text of types or definitions
+fn get_names(text: &str) -> impl Iterator<Item = String> + '_ {
+    text.split(|c: char| !c.is_alphanumeric() && c != '_')
+        .filter(|name| !name.is_empty())
+        .map(String::from)
+}
+
+/// Get the text of the type definitions, keyed by name
+fn get_type_definitions(
+    db: &RootDatabase,
+    registry: &TypeRegistry,
+) -> HashMap<String, Vec<String>> {
+    let mut definitions: HashMap<String, Vec<String>> = HashMap::new();
+    for base_type in registry.base_types() {
+        let definition = format!("B{}{:?}", base_type.name, base_type.generics);
+        definitions
+            .entry(base_type.name.clone())
+            .or_default()
+            .push(definition);
+    }
+    for cairo_struct in registry.structs() {
+        let mut definition = format!("S{}{:?}", cairo_struct.name, cairo_struct.generics);
+        for ty in cairo_struct.members_types.iter() {
+            definition.push_str(&ty.clone().get_text_without_trivia(db));
+            definition.push(',');
+        }
+        definitions
+            .entry(cairo_struct.name.clone())
+            .or_default()
+            .push(definition);
+    }
+    for cairo_enum in registry.enums() {
+        let mut definition = format!("E{}{:?}", cairo_enum.name, cairo_enum.generics);
+        for ty in cairo_enum.variants_types.iter() {
+            definition.push_str(&ty.clone().get_text_without_trivia(db));
+            definition.push(',');
+        }
+        definitions
+            .entry(cairo_enum.name.clone())
+            .or_default()
+            .push(definition);
+    }
+    for cairo_type_alias in registry.type_aliases() {
+        let mut definition = format!("T{}{:?}", cairo_type_alias.name, cairo_type_alias.generics);
+        definition.push_str(
+            &cairo_type_alias
+                .aliased_type
+                .clone()
+                .get_text_without_trivia(db),
+        );
+        definitions
+            .entry(cairo_type_alias.name.clone())
+            .or_default()
+            .push(definition);
+    }
+    definitions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOURCE: &str = "
+        struct Point {
+            x: felt252,
+            y: felt252,
+        }
+
+        trait IShape {
+            fn move_to(point: Point) -> bool;
+        }
+
+        trait ICounter {
+            fn increase(amount: u128);
+        }
+
+        trait IToken {
+            fn transfer(recipient: felt252, amount: u256) -> bool;
+        }
+    ";
+
+    /// Write the source to the file and get the interfaces and the recomputed paths
+    fn get_interfaces(
+        db: &mut RootDatabase,
+        cache: &mut InterfacesCache,
+        path: &Path,
+        source: &str,
+    ) -> (HashMap<String, String>, Vec<String>, Vec<String>) {
+        std::fs::write(path, source).unwrap();
+        let config = Config::default();
+        let sources = get_cairo_sources(db, &[path.to_path_buf()], &config).unwrap();
+        let interfaces = cache.get_interfaces(db, &sources, &config).unwrap();
+        let paths = interfaces.iter().map(|(path, _)| path.clone()).collect();
+        let ids = interfaces
+            .into_iter()
+            .map(|(path, interface)| (path, format!("0x{:x}", interface.id)))
+            .collect();
+        (ids, paths, cache.recomputed.clone())
+    }
+
+    #[test]
+    fn cache_recomputes_only_the_affected_traits() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("lib.cairo");
+        let mut db = get_database_with_starknet_plugin();
+        let mut cache = InterfacesCache::default();
+
+        let (ids, paths, recomputed) = get_interfaces(&mut db, &mut cache, &path, SOURCE);
+        assert_eq!(paths, ["IShape", "ICounter", "IToken"]);
+        assert_eq!(recomputed, paths);
+
+        // Editing a trait recomputes only that trait
+        let source = SOURCE.replace("amount: u128", "amount: u64");
+        let (new_ids, paths, recomputed) = get_interfaces(&mut db, &mut cache, &path, &source);
+        assert_eq!(recomputed, ["ICounter"]);
+        assert_eq!(
+            get_changed_ids(&ids, &new_ids, &paths),
+            [format!(
+                "  ICounter: {} -> {}",
+                ids["ICounter"], new_ids["ICounter"]
+            )]
+        );
+
+        // Editing a type recomputes only the traits depending on it
+        let ids = new_ids;
+        let source = source.replace("y: felt252", "y: u128");
+        let (new_ids, paths, recomputed) = get_interfaces(&mut db, &mut cache, &path, &source);
+        assert_eq!(recomputed, ["IShape"]);
+        assert_eq!(
+            get_changed_ids(&ids, &new_ids, &paths),
+            [format!(
+                "  IShape: {} -> {}",
+                ids["IShape"], new_ids["IShape"]
+            )]
+        );
+
+        // Nothing is recomputed when the file is saved without changes
+        let (_, _, recomputed) = get_interfaces(&mut db, &mut cache, &path, &source);
+        assert!(recomputed.is_empty());
+    }
+
+    #[test]
+    fn changed_ids_list_changed_added_and_removed_paths() {
+        let get_ids = |ids: &[(&str, &str)]| -> HashMap<String, String> {
+            ids.iter()
+                .map(|(path, id)| (path.to_string(), id.to_string()))
+                .collect()
+        };
+        let previous_ids = get_ids(&[
+            ("a::IFoo", "0x1"),
+            ("b::IFoo", "0x2"),
+            ("IRemoved", "0x3"),
+            ("ISame", "0x4"),
+        ]);
+        let ids = get_ids(&[
+            ("ISame", "0x4"),
+            ("b::IFoo", "0x5"),
+            ("IAdded", "0x6"),
+            ("a::IFoo", "0x1"),
+        ]);
+        let paths = ["ISame", "b::IFoo", "IAdded", "a::IFoo"].map(String::from);
+
+        assert_eq!(
+            get_changed_ids(&previous_ids, &ids, &paths),
+            [
+                "  b::IFoo: 0x2 -> 0x5",
+                "  IAdded: added (0x6)",
+                "  IRemoved: removed (0x3)",
+            ]
+        );
+        assert!(get_changed_ids(&ids, &ids, &paths).is_empty());
+    }
+}
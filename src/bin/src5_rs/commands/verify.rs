@@ -0,0 +1,109 @@
+use anyhow::{bail, Ok, Result};
+use async_trait::async_trait;
+use clap::Parser;
+use src5_rs::parser::ast::get_database_with_starknet_plugin;
+
+use super::lock::{get_locked_interfaces, get_lockfile_path};
+use super::parse::{get_cairo_sources, load_config_and_inputs};
+use super::CliCommand;
+use crate::lockfile::{LockedInterface, Lockfile};
+
+#[derive(Parser, Debug)]
+pub struct Verify {
+    #[clap(help = "File path to the Cairo source code (defaults to the configured inputs)")]
+    pub cairo_path: Option<String>,
+    #[clap(
+        long,
+        help = "Path to the lockfile (defaults to the src5.lock next to the configuration file)"
+    )]
+    pub lockfile: Option<String>,
+    #[clap(
+        short,
+        long,
+        help = "Path to the configuration file (defaults to the src5.toml found upward from the input)"
+    )]
+    pub config: Option<String>,
+    #[clap(
+        long = "extern-type",
+        value_name = "TYPE",
+        help = "Declare an opaque type, with its generic parameters if any (ex: Vault<T>)"
+    )]
+    pub extern_types: Vec<String>,
+}
+
+#[async_trait]
+impl CliCommand for Verify {
+    // Parse a file checking that the interface ids recorded in the lockfile are unchanged
+    async fn run(&self) -> Result<()> {
+        let (config, inputs) = load_config_and_inputs(
+            self.cairo_path.as_deref(),
+            self.config.as_deref(),
+            &self.extern_types,
+        )?;
+        let lockfile = Lockfile::load(&get_lockfile_path(self.lockfile.as_deref(), &config))?;
+
+        // Create a new database with the StarkNet plugin
+        let mut db = get_database_with_starknet_plugin();
+
+        let sources = get_cairo_sources(&mut db, &inputs, &config)?;
+        let interfaces = get_locked_interfaces(&db, &sources, &config)?;
+
+        let mut changes_count = 0;
+        for locked in lockfile.interfaces.iter() {
+            match interfaces
+                .iter()
+                .find(|interface| interface.path == locked.path)
+            {
+                None => {
+                    println!("{}: error: removed (locked id {})", locked.path, locked.id);
+                    changes_count += 1;
+                }
+                Some(interface) if interface.id != locked.id => {
+                    println!(
+                        "{}: error: id changed from {} to {}",
+                        locked.path, locked.id, interface.id
+                    );
+                    print_signatures_diff(locked, interface);
+                    changes_count += 1;
+                }
+                Some(_) => {}
+            }
+        }
+        // The lockfile records every trait, so the new ones are changes too
+        for interface in interfaces.iter() {
+            if lockfile.get_interface(&interface.path).is_none() {
+                println!(
+                    "{}: error: not locked (id {})",
+                    interface.path, interface.id
+                );
+                changes_count += 1;
+            }
+        }
+
+        if changes_count > 0 {
+            bail!(
+                "Found {} changed interfaces, run `src5_rs lock` for accepting the changes",
+                changes_count
+            );
+        }
+        println!(
+            "The {} locked interfaces are unchanged",
+            lockfile.interfaces.len()
+        );
+        Ok(())
+    }
+}
+
+/// Print the locked signatures that are no longer found, and the new ones
+fn print_signatures_diff(locked: &LockedInterface, interface: &LockedInterface) {
+    for signature in locked.signatures.iter() {
+        if !interface.signatures.contains(signature) {
+            println!("  - {signature}");
+        }
+    }
+    for signature in interface.signatures.iter() {
+        if !locked.signatures.contains(signature) {
+            println!("  + {signature}");
+        }
+    }
+}
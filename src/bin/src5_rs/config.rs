@@ -0,0 +1,207 @@
+// Module for handling the project configuration file
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Ok, Result};
+use clap::ValueEnum;
+use serde::Deserialize;
+use src5_rs::parser::utils::to_snake_case;
+
+pub const CONFIG_FILE_NAME: &str = "src5.toml";
+
+/// Cairo versions whose syntax is supported by the parser
+const SUPPORTED_CAIRO_VERSIONS: &[&str] = &["2.0"];
+
+#[derive(Deserialize, Debug, Default)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// Cairo files to parse, relative to the configuration file
+    pub inputs: Vec<PathBuf>,
+    pub exclude: Exclude,
+    pub format: Option<OutputFormat>,
+    pub constants: ConstantsNaming,
+    /// Cairo source code with type definitions available to every input
+    pub extra_types: Option<String>,
+    /// Opaque types, with their generic parameters if any (ex: `Vault<T>`)
+    pub extern_types: Vec<String>,
+    pub cairo_version: Option<String>,
+    pub lint: LintConfig,
+    /// Directory containing the configuration file
+    #[serde(skip)]
+    pub root_dir: PathBuf,
+}
+
+#[derive(Deserialize, Debug, Default)]
+#[serde(default, deny_unknown_fields)]
+pub struct Exclude {
+    /// Trait names, or paths from the input module (ex: `account::IAccount`)
+    pub traits: Vec<String>,
+    /// Module paths from the input module (ex: `tests` or `account::mocks`)
+    pub modules: Vec<String>,
+}
+
+#[derive(Deserialize, ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum OutputFormat {
+    #[default]
+    Table,
+    Json,
+    /// Cairo constants with the interface ids
+    Cairo,
+    /// TypeScript module exporting the interface ids and selectors
+    #[value(alias = "ts")]
+    #[serde(alias = "ts")]
+    Typescript,
+    /// Markdown sections documenting each interface
+    #[value(alias = "md")]
+    #[serde(alias = "md")]
+    Markdown,
+}
+
+#[derive(Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum NamingCase {
+    /// IERC721Metadata -> IERC721_METADATA
+    #[default]
+    ScreamingSnake,
+    /// IERC721Metadata -> ierc721_metadata
+    Snake,
+    /// IERC721Metadata -> IERC721Metadata
+    Pascal,
+}
+
+/// Naming convention for the constants holding the interface ids
+#[derive(Deserialize, Debug)]
+#[serde(default, deny_unknown_fields)]
+pub struct ConstantsNaming {
+    pub case: NamingCase,
+    pub prefix: String,
+    pub suffix: String,
+}
+
+impl Default for ConstantsNaming {
+    fn default() -> Self {
+        ConstantsNaming {
+            case: NamingCase::ScreamingSnake,
+            prefix: String::new(),
+            suffix: "_ID".into(),
+        }
+    }
+}
+
+#[derive(Deserialize, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+pub enum LintLevel {
+    Off,
+    Warning,
+    Error,
+}
+
+/// Level of each lint rule
+#[derive(Deserialize, Debug)]
+#[serde(default, deny_unknown_fields)]
+pub struct LintConfig {
+    /// Traits without functions, whose interface id is 0
+    pub empty_trait: LintLevel,
+    /// Functions with the same name in different interfaces of a contract
+    pub selector_clash: LintLevel,
+    /// Different traits with the same interface id
+    pub duplicate_id: LintLevel,
+    /// Function types nested deeper than `max_type_depth`
+    pub deep_type: LintLevel,
+    pub max_type_depth: usize,
+    /// Function types that can't be resolved
+    pub unresolved_type: LintLevel,
+}
+
+impl Default for LintConfig {
+    fn default() -> Self {
+        LintConfig {
+            empty_trait: LintLevel::Error,
+            selector_clash: LintLevel::Error,
+            duplicate_id: LintLevel::Error,
+            deep_type: LintLevel::Warning,
+            max_type_depth: 4,
+            unresolved_type: LintLevel::Error,
+        }
+    }
+}
+
+impl ConstantsNaming {
+    /// Get the name of the constant holding the interface id of a trait
+    pub fn get_constant_name(&self, trait_name: &str) -> String {
+        let name = match self.case {
+            NamingCase::ScreamingSnake => to_snake_case(trait_name).to_uppercase(),
+            NamingCase::Snake => to_snake_case(trait_name),
+            NamingCase::Pascal => trait_name.to_string(),
+        };
+        format!("{}{}{}", self.prefix, name, self.suffix)
+    }
+}
+
+impl Config {
+    /// Load the configuration file, validating the supported Cairo version
+    pub fn load(path: &Path) -> Result<Config> {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        let mut config: Config = toml::from_str(&content)
+            .with_context(|| format!("Invalid configuration file {}", path.display()))?;
+        config.root_dir = path.parent().map(Path::to_path_buf).unwrap_or_default();
+
+        if let Some(cairo_version) = &config.cairo_version {
+            let supported = SUPPORTED_CAIRO_VERSIONS.iter().any(|version| {
+                cairo_version == version || cairo_version.starts_with(&format!("{version}."))
+            });
+            if !supported {
+                bail!(
+                    "Unsupported Cairo version {} (supported: {})",
+                    cairo_version,
+                    SUPPORTED_CAIRO_VERSIONS.join(", ")
+                );
+            }
+        }
+        Ok(config)
+    }
+
+    /// Find the configuration file in the directory or any of its ancestors, and load it.
+    /// The default configuration is returned if there is no configuration file.
+    pub fn discover(dir: &Path) -> Result<Config> {
+        // An empty path (ex: the parent of a bare file name) is the current directory
+        let dir = if dir.as_os_str().is_empty() {
+            std::env::current_dir()?
+        } else {
+            dir.canonicalize().unwrap_or_else(|_| dir.to_path_buf())
+        };
+        for ancestor in dir.ancestors() {
+            let path = ancestor.join(CONFIG_FILE_NAME);
+            if path.is_file() {
+                return Config::load(&path);
+            }
+        }
+        Ok(Config::default())
+    }
+
+    /// Get the configured inputs paths
+    pub fn get_inputs(&self) -> Vec<PathBuf> {
+        self.inputs
+            .iter()
+            .map(|input| self.root_dir.join(input))
+            .collect()
+    }
+
+    /// Check if a trait is excluded, given the path of the module defining it
+    pub fn is_excluded(&self, module_path: &[String], trait_name: &str) -> bool {
+        let module_path = module_path.join("::");
+        let trait_path = if module_path.is_empty() {
+            trait_name.to_string()
+        } else {
+            format!("{module_path}::{trait_name}")
+        };
+        self.exclude
+            .traits
+            .iter()
+            .any(|excluded| excluded == trait_name || *excluded == trait_path)
+            || self.exclude.modules.iter().any(|excluded| {
+                module_path == *excluded || module_path.starts_with(&format!("{excluded}::"))
+            })
+    }
+}
@@ -0,0 +1,90 @@
+// Module for handling the lockfile recording the interface ids
+use std::path::Path;
+
+use anyhow::{Context, Ok, Result};
+use serde::{Deserialize, Serialize};
+
+pub const LOCK_FILE_NAME: &str = "src5.lock";
+
+const LOCK_FILE_HEADER: &str =
+    "# Interface ids recorded by `src5_rs lock`, checked by `src5_rs verify`\n\n";
+
+#[derive(Serialize, Deserialize, Debug, Default, PartialEq, Eq)]
+pub struct Lockfile {
+    #[serde(default, rename = "interface")]
+    pub interfaces: Vec<LockedInterface>,
+}
+
+/// The recorded id of a trait, with the signatures it was computed from
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct LockedInterface {
+    /// Path of the trait, from the input module (ex: `account::interface::IAccount`)
+    pub path: String,
+    pub id: String,
+    pub signatures: Vec<String>,
+}
+
+impl Lockfile {
+    pub fn load(path: &Path) -> Result<Lockfile> {
+        let content = std::fs::read_to_string(path).with_context(|| {
+            format!(
+                "Failed to read {}, run `src5_rs lock` for creating it",
+                path.display()
+            )
+        })?;
+        let lockfile = toml::from_str(&content)
+            .with_context(|| format!("Invalid lockfile {}", path.display()))?;
+        Ok(lockfile)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let content = format!("{}{}", LOCK_FILE_HEADER, toml::to_string_pretty(self)?);
+        std::fs::write(path, content)
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+        Ok(())
+    }
+
+    pub fn get_interface(&self, path: &str) -> Option<&LockedInterface> {
+        self.interfaces
+            .iter()
+            .find(|interface| interface.path == path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn saved_lockfile_is_loaded_back() {
+        let lockfile = Lockfile {
+            interfaces: vec![
+                LockedInterface {
+                    path: "IFoo".into(),
+                    id: "0x1".into(),
+                    signatures: vec!["foo()->()".into()],
+                },
+                LockedInterface {
+                    path: "accounts::signers::ISigner".into(),
+                    id: "0x2".into(),
+                    signatures: vec![],
+                },
+            ],
+        };
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(LOCK_FILE_NAME);
+        lockfile.save(&path).unwrap();
+
+        assert!(std::fs::read_to_string(&path)
+            .unwrap()
+            .starts_with(LOCK_FILE_HEADER));
+        assert_eq!(Lockfile::load(&path).unwrap(), lockfile);
+    }
+
+    #[test]
+    fn missing_lockfile_asks_for_locking() {
+        let dir = tempfile::tempdir().unwrap();
+        let error = Lockfile::load(&dir.path().join(LOCK_FILE_NAME)).unwrap_err();
+        assert!(error.to_string().contains("run `src5_rs lock`"));
+    }
+}
@@ -3,6 +3,9 @@ extern crate prettytable;
 
 mod cli;
 mod commands;
+mod config;
+mod lockfile;
+mod output;
 
 use anyhow::{Ok, Result};
 use dotenv::dotenv;
@@ -18,10 +21,35 @@ async fn main() -> Result<()> {
     dotenv().ok();
 
     let cli = Cli::parse();
-    match cli.command {
+    // Parse the configured inputs when no command is given
+    match cli.command.unwrap_or_default() {
         cli::Commands::Parse(cmd) => {
             cmd.run().await?;
         }
+        cli::Commands::Contracts(cmd) => {
+            cmd.run().await?;
+        }
+        cli::Commands::Generate(cmd) => {
+            cmd.run().await?;
+        }
+        cli::Commands::Check(cmd) => {
+            cmd.run().await?;
+        }
+        cli::Commands::Lint(cmd) => {
+            cmd.run().await?;
+        }
+        cli::Commands::Comply(cmd) => {
+            cmd.run().await?;
+        }
+        cli::Commands::Lock(cmd) => {
+            cmd.run().await?;
+        }
+        cli::Commands::Verify(cmd) => {
+            cmd.run().await?;
+        }
+        cli::Commands::Explain(cmd) => {
+            cmd.run().await?;
+        }
     };
     Ok(())
 }
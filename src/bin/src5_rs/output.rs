@@ -0,0 +1,409 @@
+// Module for printing the interfaces in the supported output formats
+use anyhow::{bail, Ok, Result};
+use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use prettytable::{format, Table};
+use serde_json::json;
+use src5_rs::interface::SRC5Interface;
+use src5_rs::parser::cairo_trait::CairoNonGenericTrait;
+use src5_rs::parser::utils::{find_children, get_doc_comment};
+
+use crate::commands::{ContractInterface, ContractInterfaces};
+use crate::config::{Config, OutputFormat};
+
+/// The documentation of a trait, from its source code
+pub struct TraitDocs {
+    /// Cairo code of the trait
+    pub definition: String,
+    pub doc: Option<String>,
+    /// Cairo declaration and doc comment of each function
+    pub functions: Vec<(String, Option<String>)>,
+}
+
+/// Get the documentation of the traits, for the markdown output
+pub fn get_traits_docs(db: &RootDatabase, traits: &[CairoNonGenericTrait]) -> Vec<TraitDocs> {
+    traits
+        .iter()
+        .map(|cairo_trait| TraitDocs {
+            definition: cairo_trait.node.clone().get_text_without_trivia(db),
+            doc: get_doc_comment(db, &cairo_trait.node),
+            functions: cairo_trait
+                .functions
+                .iter()
+                .map(|function| {
+                    let declaration =
+                        find_children(db, &function.node, SyntaxKind::FunctionDeclaration)
+                            .map(|declaration| declaration.get_text_without_trivia(db))
+                            .unwrap_or_default();
+                    (
+                        get_single_line(&declaration),
+                        get_doc_comment(db, &function.node),
+                    )
+                })
+                .collect(),
+        })
+        .collect()
+}
+
+/// Print the interfaces in the given format. The traits documentation is only used by the
+/// markdown output.
+pub fn print_interfaces(
+    interfaces: &[SRC5Interface],
+    docs: Option<&[TraitDocs]>,
+    output_format: OutputFormat,
+    config: &Config,
+) -> Result<()> {
+    match output_format {
+        OutputFormat::Table => print_interfaces_table(interfaces),
+        OutputFormat::Json => println!("{}", get_interfaces_json(interfaces)?),
+        OutputFormat::Cairo => print!("{}", get_interfaces_cairo_constants(interfaces, config)),
+        OutputFormat::Typescript => print!("{}", get_interfaces_typescript_module(interfaces)?),
+        OutputFormat::Markdown => print!("{}", get_interfaces_markdown(interfaces, docs)),
+    }
+    Ok(())
+}
+
+pub fn print_interfaces_table(interfaces: &[SRC5Interface]) {
+    println!();
+    let mut trait_table = Table::new();
+    trait_table.set_format(*format::consts::FORMAT_NO_LINESEP_WITH_TITLE);
+    trait_table.set_titles(row![
+        bFg->"SRC5 Function Signature:",
+        bFg->"Extended Function Selector:",
+        bFg->"Entry Point Selector:"
+    ]);
+
+    for (i, interface) in interfaces.iter().enumerate() {
+        if i > 0 {
+            trait_table.add_empty_row();
+        }
+        trait_table.add_row(row![bFg->interface.name]);
+        for function in &interface.functions {
+            trait_table.add_row(row![
+                function.signature,
+                format!("0x{:x}", function.selector),
+                format!("0x{:x}", function.entry_point_selector)
+            ]);
+        }
+        trait_table.add_row(row![bFg->format!("Id: 0x{:x}", interface.id)]);
+    }
+    trait_table.printstd();
+}
+
+fn get_interfaces_json(interfaces: &[SRC5Interface]) -> Result<String> {
+    let interfaces: Vec<_> = interfaces
+        .iter()
+        .map(|interface| {
+            let functions: Vec<_> = interface
+                .functions
+                .iter()
+                .map(|function| {
+                    json!({
+                        "name": function.name,
+                        "signature": function.signature,
+                        "selector": format!("0x{:x}", function.selector),
+                        "entry_point_selector": format!("0x{:x}", function.entry_point_selector),
+                    })
+                })
+                .collect();
+            json!({
+                "name": interface.name,
+                "functions": functions,
+                "id": format!("0x{:x}", interface.id),
+            })
+        })
+        .collect();
+    Ok(serde_json::to_string_pretty(&interfaces)?)
+}
+
+fn get_interfaces_cairo_constants(interfaces: &[SRC5Interface], config: &Config) -> String {
+    let mut constants = String::new();
+    for interface in interfaces.iter() {
+        constants.push_str(&format!(
+            "const {}: felt252 = 0x{:x};\n",
+            config.constants.get_constant_name(&interface.name),
+            interface.id
+        ));
+    }
+    constants
+}
+
+/// Get a TypeScript module exporting an object per interface, with the id as a hex string
+/// and as a bigint, and the selector and signature of each function by name. The objects
+/// are named as the traits, so traits of different modules can't share a name.
+fn get_interfaces_typescript_module(interfaces: &[SRC5Interface]) -> Result<String> {
+    let mut module = String::from(
+        "export type SRC5Function = {\n  readonly selector: string;\n  readonly signature: string;\n};\n\n\
+         export type SRC5Interface = {\n  readonly id: string;\n  readonly idBigInt: bigint;\n  \
+         readonly functions: Readonly<Record<string, SRC5Function>>;\n};\n",
+    );
+    for (i, interface) in interfaces.iter().enumerate() {
+        if interfaces[..i]
+            .iter()
+            .any(|other| other.name == interface.name)
+        {
+            bail!(
+                "Many traits are named {}, which can't be exported twice from the TypeScript module",
+                interface.name
+            );
+        }
+        module.push('\n');
+        module.push_str(&format!("export const {} = {{\n", interface.name));
+        module.push_str(&format!("  id: \"0x{:x}\",\n", interface.id));
+        module.push_str(&format!("  idBigInt: 0x{:x}n,\n", interface.id));
+        module.push_str("  functions: {\n");
+        for function in interface.functions.iter() {
+            module.push_str(&format!(
+                "    {}: {{\n      selector: \"0x{:x}\",\n      signature: \"{}\",\n    }},\n",
+                function.name, function.selector, function.signature
+            ));
+        }
+        module.push_str("  },\n");
+        module.push_str("} as const satisfies SRC5Interface;\n");
+    }
+    Ok(module)
+}
+
+/// Get a markdown section per interface, with the trait doc comment and definition, a
+/// table of the functions and the interface id
+fn get_interfaces_markdown(interfaces: &[SRC5Interface], docs: Option<&[TraitDocs]>) -> String {
+    let mut markdown = String::new();
+    for (i, interface) in interfaces.iter().enumerate() {
+        let trait_docs = docs.and_then(|docs| docs.get(i));
+        if i > 0 {
+            markdown.push('\n');
+        }
+        markdown.push_str(&format!("## {}\n\n", interface.name));
+        if let Some(trait_docs) = trait_docs {
+            if let Some(doc) = &trait_docs.doc {
+                markdown.push_str(&format!("{doc}\n\n"));
+            }
+            markdown.push_str(&format!("```cairo\n{}\n```\n\n", trait_docs.definition));
+        }
+
+        // The description column is only added for documented functions
+        let functions_docs = trait_docs.map(|trait_docs| &trait_docs.functions);
+        let has_descriptions =
+            functions_docs.is_some_and(|functions| functions.iter().any(|(_, doc)| doc.is_some()));
+        if has_descriptions {
+            markdown.push_str("| Function | Description | SRC5 Signature | Selector |\n");
+            markdown.push_str("| --- | --- | --- | --- |\n");
+        } else {
+            markdown.push_str("| Function | SRC5 Signature | Selector |\n");
+            markdown.push_str("| --- | --- | --- |\n");
+        }
+        for (j, function) in interface.functions.iter().enumerate() {
+            let (declaration, doc) = functions_docs
+                .and_then(|functions| functions.get(j))
+                .map(|(declaration, doc)| (declaration.clone(), doc.clone()))
+                .unwrap_or_else(|| (format!("fn {}", function.name), None));
+            markdown.push_str(&format!("| `{}` |", escape_markdown_cell(&declaration)));
+            if has_descriptions {
+                let description = doc.map(|doc| get_single_line(&doc)).unwrap_or_default();
+                markdown.push_str(&format!(" {} |", escape_markdown_cell(&description)));
+            }
+            markdown.push_str(&format!(
+                " `{}` | `0x{:x}` |\n",
+                escape_markdown_cell(&function.signature),
+                function.selector
+            ));
+        }
+        markdown.push_str(&format!("\n**Interface ID:** `0x{:x}`\n", interface.id));
+    }
+    markdown
+}
+
+/// Join the lines of a text, collapsing the whitespace (ex: for multiline declarations)
+fn get_single_line(text: &str) -> String {
+    text.split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .replace("( ", "(")
+        .replace(", )", ")")
+        .replace(" )", ")")
+}
+
+fn escape_markdown_cell(text: &str) -> String {
+    text.replace('|', "\\|")
+}
+
+pub fn print_contracts(
+    contracts: &[ContractInterfaces],
+    output_format: OutputFormat,
+) -> Result<()> {
+    match output_format {
+        OutputFormat::Table => print_contracts_table(contracts),
+        OutputFormat::Json => println!("{}", get_contracts_json(contracts)?),
+        OutputFormat::Cairo => bail!("The cairo output format is not supported for contracts"),
+        OutputFormat::Typescript => {
+            bail!("The typescript output format is not supported for contracts")
+        }
+        OutputFormat::Markdown => {
+            bail!("The markdown output format is not supported for contracts")
+        }
+    }
+    Ok(())
+}
+
+fn print_contracts_table(contracts: &[ContractInterfaces]) {
+    println!();
+    let mut contract_table = Table::new();
+    contract_table.set_format(*format::consts::FORMAT_NO_LINESEP_WITH_TITLE);
+    contract_table.set_titles(row![bFg->"Interface:", bFg->"Impl:", bFg->"Interface Id:"]);
+
+    for (i, contract) in contracts.iter().enumerate() {
+        if i > 0 {
+            contract_table.add_empty_row();
+        }
+        contract_table.add_row(row![bFg->format!("Contract: {}", contract.name)]);
+        for interface in &contract.interfaces {
+            contract_table.add_row(row![
+                interface.trait_name.as_deref().unwrap_or("?"),
+                interface.impl_name,
+                get_contract_interface_id(interface).unwrap_or_else(|| "-".into())
+            ]);
+        }
+    }
+    contract_table.printstd();
+}
+
+fn get_contracts_json(contracts: &[ContractInterfaces]) -> Result<String> {
+    let contracts: Vec<_> = contracts
+        .iter()
+        .map(|contract| {
+            let interfaces: Vec<_> = contract
+                .interfaces
+                .iter()
+                .map(|interface| {
+                    json!({
+                        "name": interface.trait_name,
+                        "impl": interface.impl_name,
+                        "id": get_contract_interface_id(interface),
+                    })
+                })
+                .collect();
+            json!({
+                "name": contract.name,
+                "module_path": contract.module_path.join("::"),
+                "interfaces": interfaces,
+            })
+        })
+        .collect();
+    Ok(serde_json::to_string_pretty(&contracts)?)
+}
+
+fn get_contract_interface_id(interface: &ContractInterface) -> Option<String> {
+    let interface = interface.interface.as_ref()?;
+    Some(format!("0x{:x}", interface.id))
+}
+
+#[cfg(test)]
+mod tests {
+    use src5_rs::interface::get_src5_interface;
+    use src5_rs::parser::ast::{get_database_with_starknet_plugin, get_syntax_tree};
+    use src5_rs::parser::cairo_trait::get_non_generic_traits;
+    use src5_rs::type_registry::TypeRegistry;
+
+    use super::*;
+
+    const SOURCE: &str = "\
+/// Fungible tokens
+trait IToken {
+    /// Move the tokens | fails
+    /// without balance
+    fn transfer(
+        recipient: felt252,
+        amount: u256,
+    ) -> bool;
+    fn total_supply() -> u256;
+}
+
+trait ICounter {
+    fn get() -> u128;
+}
+";
+
+    const TOKEN_MARKDOWN: &str = "\
+## IToken
+
+Fungible tokens
+
+```cairo
+trait IToken {
+    /// Move the tokens | fails
+    /// without balance
+    fn transfer(
+        recipient: felt252,
+        amount: u256,
+    ) -> bool;
+    fn total_supply() -> u256;
+}
+```
+
+| Function | Description | SRC5 Signature | Selector |
+| --- | --- | --- | --- |
+| `fn transfer(recipient: felt252, amount: u256) -> bool` | Move the tokens \\| fails without balance | `transfer(felt252,(u128,u128))->E((),())` | `0xa4dd6a386f6ac48617e2cb12c05b28ca646c383c6db6e3bca52e9d87b3ae6` |
+| `fn total_supply() -> u256` |  | `total_supply()->(u128,u128)` | `0x2eda872f8c509ee569624cc2bc351ac799dde8fe41d2f650a05c51d7bcfb1be` |
+
+**Interface ID:** `0x2e7e5a45b43ff421ef75ae09aef541ef53b984c67dbf40b31cf97f4a3b48b58`
+";
+
+    fn get_interfaces_and_docs() -> (Vec<SRC5Interface>, Vec<TraitDocs>) {
+        let db = get_database_with_starknet_plugin();
+        let tree = get_syntax_tree(&db, SOURCE.into());
+        let mut registry = TypeRegistry::with_corelib(&db);
+        registry.register_syntax_tree(&db, &tree);
+        let traits = get_non_generic_traits(&db, &tree);
+        let interfaces = traits
+            .iter()
+            .map(|cairo_trait| get_src5_interface(&db, cairo_trait, &registry).unwrap())
+            .collect();
+        (interfaces, get_traits_docs(&db, &traits))
+    }
+
+    #[test]
+    fn markdown_renders_the_traits_docs() {
+        let (interfaces, docs) = get_interfaces_and_docs();
+
+        // The description column is only added to the traits with documented functions
+        let counter_markdown = "\
+## ICounter
+
+```cairo
+trait ICounter {
+    fn get() -> u128;
+}
+```
+
+| Function | SRC5 Signature | Selector |
+| --- | --- | --- |
+| `fn get() -> u128` | `get()->u128` | `0x1a8852b3f7e895fc7318037385e4adc2330dde7d063fe4d2f21d8100debad64` |
+
+**Interface ID:** `0x1a8852b3f7e895fc7318037385e4adc2330dde7d063fe4d2f21d8100debad64`
+";
+        assert_eq!(
+            get_interfaces_markdown(&interfaces, Some(&docs)),
+            format!("{TOKEN_MARKDOWN}\n{counter_markdown}")
+        );
+    }
+
+    #[test]
+    fn markdown_aligns_the_docs_by_index() {
+        let (interfaces, docs) = get_interfaces_and_docs();
+
+        // The interfaces without docs only show the function names
+        let counter_markdown = "\
+## ICounter
+
+| Function | SRC5 Signature | Selector |
+| --- | --- | --- |
+| `fn get` | `get()->u128` | `0x1a8852b3f7e895fc7318037385e4adc2330dde7d063fe4d2f21d8100debad64` |
+
+**Interface ID:** `0x1a8852b3f7e895fc7318037385e4adc2330dde7d063fe4d2f21d8100debad64`
+";
+        assert_eq!(
+            get_interfaces_markdown(&interfaces, Some(&docs[..1])),
+            format!("{TOKEN_MARKDOWN}\n{counter_markdown}")
+        );
+    }
+}
@@ -0,0 +1,73 @@
+// Module exposing the interfaces computation to Python
+use std::path::PathBuf;
+
+use num_bigint::BigUint;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList};
+
+use crate::interface::{get_src5_interfaces_from_source, SRC5Interface};
+use crate::selector;
+
+/// Convert an interface into a dict, with the selectors and the id as integers
+fn get_interface_dict(py: Python<'_>, interface: SRC5Interface) -> PyResult<Bound<'_, PyDict>> {
+    let functions = PyList::empty(py);
+    for function in interface.functions {
+        let function_dict = PyDict::new(py);
+        function_dict.set_item("name", function.name)?;
+        function_dict.set_item("signature", function.signature)?;
+        function_dict.set_item("selector", function.selector)?;
+        function_dict.set_item("entry_point_selector", function.entry_point_selector)?;
+        functions.append(function_dict)?;
+    }
+    let interface_dict = PyDict::new(py);
+    interface_dict.set_item("name", interface.name)?;
+    interface_dict.set_item("functions", functions)?;
+    interface_dict.set_item("id", interface.id)?;
+    Ok(interface_dict)
+}
+
+/// Compute the SRC5 interfaces of the non generic traits defined in Cairo source code
+#[pyfunction]
+fn compute_interfaces<'py>(py: Python<'py>, source: &str) -> PyResult<Vec<Bound<'py, PyDict>>> {
+    get_src5_interfaces_from_source(source)
+        .map_err(|error| PyValueError::new_err(error.to_string()))?
+        .into_iter()
+        .map(|interface| get_interface_dict(py, interface))
+        .collect()
+}
+
+/// Compute the SRC5 interfaces of the non generic traits defined in a Cairo file
+#[pyfunction]
+fn compute_interfaces_from_file(py: Python<'_>, path: PathBuf) -> PyResult<Vec<Bound<'_, PyDict>>> {
+    let source = std::fs::read_to_string(path)?;
+    compute_interfaces(py, &source)
+}
+
+/// Get the Extended Function Selector from the SRC5 signature of a function
+#[pyfunction]
+fn get_selector_from_signature(signature: &str) -> BigUint {
+    selector::get_selector_from_signature(signature)
+}
+
+/// Get the Starknet entry point selector from the name of a function
+#[pyfunction]
+fn get_entry_point_selector(name: &str) -> BigUint {
+    selector::get_entry_point_selector(name)
+}
+
+/// Get the interface id from the Extended Function Selectors of its functions
+#[pyfunction]
+fn get_interface_id(selectors: Vec<BigUint>) -> BigUint {
+    selector::get_interface_id(&selectors)
+}
+
+#[pymodule]
+fn src5_rs(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_function(wrap_pyfunction!(compute_interfaces, module)?)?;
+    module.add_function(wrap_pyfunction!(compute_interfaces_from_file, module)?)?;
+    module.add_function(wrap_pyfunction!(get_selector_from_signature, module)?)?;
+    module.add_function(wrap_pyfunction!(get_entry_point_selector, module)?)?;
+    module.add_function(wrap_pyfunction!(get_interface_id, module)?)?;
+    Ok(())
+}
@@ -0,0 +1,77 @@
+// Module exposing the interfaces computation to JavaScript
+use serde::Serialize;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+
+use crate::interface::{get_src5_interfaces_from_source, SRC5Interface};
+
+#[wasm_bindgen(typescript_custom_section)]
+const TS_TYPES: &str = r#"
+export interface SRC5Function {
+  name: string;
+  signature: string;
+  /** Extended Function Selector, as an hex string */
+  selector: string;
+  /** Starknet entry point selector, as an hex string */
+  entryPointSelector: string;
+}
+
+export interface SRC5Interface {
+  name: string;
+  functions: SRC5Function[];
+  /** Interface id, as an hex string */
+  id: string;
+}
+"#;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(typescript_type = "SRC5Interface[]")]
+    pub type Interfaces;
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct JsFunction {
+    name: String,
+    signature: String,
+    selector: String,
+    entry_point_selector: String,
+}
+
+#[derive(Serialize)]
+struct JsInterface {
+    name: String,
+    functions: Vec<JsFunction>,
+    id: String,
+}
+
+impl From<SRC5Interface> for JsInterface {
+    fn from(interface: SRC5Interface) -> Self {
+        JsInterface {
+            name: interface.name,
+            functions: interface
+                .functions
+                .into_iter()
+                .map(|function| JsFunction {
+                    name: function.name,
+                    signature: function.signature,
+                    selector: format!("0x{:x}", function.selector),
+                    entry_point_selector: format!("0x{:x}", function.entry_point_selector),
+                })
+                .collect(),
+            id: format!("0x{:x}", interface.id),
+        }
+    }
+}
+
+/// Compute the SRC5 interfaces of the non generic traits defined in Cairo source code
+#[wasm_bindgen(js_name = computeInterfaces)]
+pub fn compute_interfaces(source: &str) -> Result<Interfaces, JsError> {
+    let interfaces: Vec<JsInterface> = get_src5_interfaces_from_source(source)
+        .map_err(|error| JsError::new(&error.to_string()))?
+        .into_iter()
+        .map(JsInterface::from)
+        .collect();
+    Ok(serde_wasm_bindgen::to_value(&interfaces)?.unchecked_into())
+}
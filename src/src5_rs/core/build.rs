@@ -0,0 +1,87 @@
+// Module for generating Rust constants with the interface ids from a build script
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Ok, Result};
+use num_bigint::BigUint;
+
+use crate::core::interface::{get_src5_interfaces_from_trees, read_cairo_files, SRC5Interface};
+use crate::core::parser::utils::to_snake_case;
+use crate::core::selector::get_felt_bytes;
+use crate::parser::ast::get_database_with_starknet_plugin;
+
+/// Compute the SRC5 interfaces of the Cairo source files and of the files of the modules
+/// they declare, and write a Rust module with their ids and selectors into `OUT_DIR`.
+/// Cargo re-runs the build script when any of the files changes. Returns the path of the
+/// written module, to be included with `include!(concat!(env!("OUT_DIR"), "/<file_name>"))`.
+pub fn write_interfaces_module<P: AsRef<Path>>(paths: &[P], file_name: &str) -> Result<PathBuf> {
+    // The given files are watched before reading them, so the build re-runs once fixed
+    for path in paths.iter() {
+        println!("cargo:rerun-if-changed={}", path.as_ref().display());
+    }
+    let out_dir = std::env::var_os("OUT_DIR")
+        .context("OUT_DIR is not set, the module must be written from a build script")?;
+
+    let db = get_database_with_starknet_plugin();
+    let files = read_cairo_files(&db, paths)?;
+    let module_files = files
+        .iter()
+        .filter(|(path, _)| !paths.iter().any(|given| given.as_ref() == path));
+    for (path, _) in module_files {
+        println!("cargo:rerun-if-changed={}", path.display());
+    }
+    let interfaces = get_src5_interfaces_from_trees(&db, files.iter().map(|(_, tree)| tree))?;
+    let out_path = Path::new(&out_dir).join(file_name);
+    std::fs::write(&out_path, get_interfaces_module(&interfaces)?)
+        .with_context(|| format!("Failed to write {}", out_path.display()))?;
+    Ok(out_path)
+}
+
+/// Get a Rust module with a submodule per interface (ex: `ierc721_metadata`), holding the
+/// interface id as `ID` and the function selectors by function name in `selectors`, as
+/// big-endian bytes
+pub fn get_interfaces_module(interfaces: &[SRC5Interface]) -> Result<String> {
+    let mut module = String::from("// SRC5 interface ids and selectors generated by src5_rs\n");
+    let mut module_names = HashSet::new();
+    for interface in interfaces.iter() {
+        let module_name = to_snake_case(&interface.name);
+        if !module_names.insert(module_name.clone()) {
+            bail!("Many interfaces are named as {}", interface.name);
+        }
+
+        module.push_str(&format!("\n/// {} interface\n", interface.name));
+        module.push_str(&format!("pub mod {module_name} {{\n"));
+        module.push_str(&format!("    /// Interface id: 0x{:x}\n", interface.id));
+        module.push_str(&format!(
+            "    pub const ID: [u8; 32] = {};\n",
+            get_bytes(&interface.id)
+        ));
+        module.push_str("\n    /// Extended function selectors\n");
+        module.push_str("    pub mod selectors {\n");
+        for (i, function) in interface.functions.iter().enumerate() {
+            if i > 0 {
+                module.push('\n');
+            }
+            module.push_str(&format!(
+                "        /// `{}`: 0x{:x}\n",
+                function.signature, function.selector
+            ));
+            module.push_str(&format!(
+                "        pub const {}: [u8; 32] = {};\n",
+                function.name.to_uppercase(),
+                get_bytes(&function.selector)
+            ));
+        }
+        module.push_str("    }\n}\n");
+    }
+    Ok(module)
+}
+
+/// Get the 32 big-endian bytes of a felt as a Rust array expression
+fn get_bytes(value: &BigUint) -> String {
+    let bytes: Vec<_> = get_felt_bytes(value)
+        .iter()
+        .map(|byte| format!("0x{byte:02x}"))
+        .collect();
+    format!("[{}]", bytes.join(", "))
+}
@@ -0,0 +1,141 @@
+// Module for computing SRC5 interfaces from Cairo traits
+use std::collections::{HashSet, VecDeque};
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Ok, Result};
+use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_syntax::node::SyntaxNode;
+use num_bigint::BigUint;
+
+use crate::core::selector::{
+    get_entry_point_selector, get_interface_id, get_selector_from_signature,
+};
+use crate::core::type_registry::TypeRegistry;
+use crate::parser::ast::{get_database_with_starknet_plugin, get_syntax_tree};
+use crate::parser::cairo_module::get_external_modules;
+use crate::parser::cairo_trait::{get_non_generic_traits, CairoNonGenericTrait};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SRC5Function {
+    pub name: String,
+    pub signature: String,
+    pub selector: BigUint,
+    /// Starknet entry point selector, from the function name only
+    pub entry_point_selector: BigUint,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SRC5Interface {
+    pub name: String,
+    pub functions: Vec<SRC5Function>,
+    pub id: BigUint,
+}
+
+/// Get the SRC5 interface from a non generic trait, computing the Extended Function
+/// Selector of each function and the interface id as the XOR of all of them
+pub fn get_src5_interface(
+    db: &RootDatabase,
+    cairo_trait: &CairoNonGenericTrait,
+    registry: &TypeRegistry,
+) -> Result<SRC5Interface> {
+    let mut functions = Vec::new();
+    for function in cairo_trait.functions.iter() {
+        let signature = function.get_efs_signature(db, registry)?;
+        let selector = get_selector_from_signature(&signature);
+        functions.push(SRC5Function {
+            name: function.name.clone(),
+            signature,
+            selector,
+            entry_point_selector: get_entry_point_selector(&function.name),
+        });
+    }
+    let id = get_interface_id(functions.iter().map(|function| &function.selector));
+    Ok(SRC5Interface {
+        name: cairo_trait.name.clone(),
+        functions,
+        id,
+    })
+}
+
+/// Get the SRC5 interfaces of the non generic traits defined in Cairo source code, using
+/// the corelib types and the types defined in the same source code
+pub fn get_src5_interfaces_from_source(source: &str) -> Result<Vec<SRC5Interface>> {
+    let db = get_database_with_starknet_plugin();
+    let tree = get_syntax_tree(&db, source.into());
+    let mut registry = TypeRegistry::with_corelib(&db);
+    registry.register_syntax_tree(&db, &tree);
+
+    get_non_generic_traits(&db, &tree)
+        .iter()
+        .map(|cairo_trait| get_src5_interface(&db, cairo_trait, &registry))
+        .collect()
+}
+
+/// Get the SRC5 interfaces of the non generic traits defined in Cairo source files and in
+/// the files of the modules they declare (transitively), using the corelib types and the
+/// types defined in any of the files
+pub fn get_src5_interfaces_from_files<P: AsRef<Path>>(paths: &[P]) -> Result<Vec<SRC5Interface>> {
+    let db = get_database_with_starknet_plugin();
+    let files = read_cairo_files(&db, paths)?;
+    get_src5_interfaces_from_trees(&db, files.iter().map(|(_, tree)| tree))
+}
+
+/// Parse the Cairo files and the files of the modules they declare, resolved like the
+/// compiler does: from the directory of the given files, and from a directory named after
+/// the file for the other ones (ex: `account/signers.cairo` for `mod signers;` in
+/// `account.cairo`)
+pub(crate) fn read_cairo_files<P: AsRef<Path>>(
+    db: &RootDatabase,
+    paths: &[P],
+) -> Result<Vec<(PathBuf, SyntaxNode)>> {
+    let mut files = Vec::new();
+    let mut visited = HashSet::new();
+    let mut pending: VecDeque<_> = paths
+        .iter()
+        .map(|path| (path.as_ref().to_path_buf(), true))
+        .collect();
+    while let Some((path, is_root)) = pending.pop_front() {
+        if !visited.insert(path.clone()) {
+            continue;
+        }
+        let source = std::fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        let tree = get_syntax_tree(db, source);
+
+        let mut modules_dir = path.parent().map(Path::to_path_buf).unwrap_or_default();
+        if !is_root {
+            modules_dir.push(path.file_stem().unwrap_or_default());
+        }
+        for external_module in get_external_modules(db, &tree) {
+            let mut module_file = modules_dir.clone();
+            module_file.extend(&external_module);
+            module_file.set_extension("cairo");
+            // Modules without a file (ex: defined by plugins) are ignored
+            if module_file.is_file() {
+                pending.push_back((module_file, false));
+            }
+        }
+        files.push((path, tree));
+    }
+    Ok(files)
+}
+
+/// Get the SRC5 interfaces of the non generic traits defined in the syntax trees, using
+/// the corelib types and the types defined in any of the trees
+pub(crate) fn get_src5_interfaces_from_trees<'a>(
+    db: &RootDatabase,
+    trees: impl Iterator<Item = &'a SyntaxNode> + Clone,
+) -> Result<Vec<SRC5Interface>> {
+    let mut registry = TypeRegistry::with_corelib(db);
+    for tree in trees.clone() {
+        registry.register_syntax_tree(db, tree);
+    }
+
+    let mut interfaces = Vec::new();
+    for tree in trees {
+        for cairo_trait in get_non_generic_traits(db, tree) {
+            interfaces.push(get_src5_interface(db, &cairo_trait, &registry)?);
+        }
+    }
+    Ok(interfaces)
+}
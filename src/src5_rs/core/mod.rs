@@ -1,3 +1,13 @@
+#[cfg(feature = "parser")]
+pub mod build;
+#[cfg(feature = "parser")]
+pub mod interface;
+#[cfg(feature = "parser")]
 pub mod parser;
+#[cfg(feature = "parser")]
+pub mod plugin;
 pub mod selector;
+#[cfg(feature = "parser")]
 pub mod src5_type;
+#[cfg(feature = "parser")]
+pub mod type_registry;
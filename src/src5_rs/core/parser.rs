@@ -1,7 +1,11 @@
 pub mod ast;
 pub mod cairo_base_type;
+pub mod cairo_constant;
+pub mod cairo_contract;
 pub mod cairo_enum;
 pub mod cairo_function;
+pub mod cairo_module;
 pub mod cairo_struct;
 pub mod cairo_trait;
+pub mod cairo_type_alias;
 pub mod utils;
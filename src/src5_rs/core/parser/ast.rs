@@ -1,10 +1,12 @@
 use cairo_lang_compiler::db::RootDatabase;
 use cairo_lang_diagnostics::DiagnosticsBuilder;
-use cairo_lang_filesystem::db::FilesGroup;
-use cairo_lang_filesystem::ids::{FileLongId, VirtualFile};
+use cairo_lang_filesystem::db::{FilesGroup, FilesGroupEx};
+use cairo_lang_filesystem::ids::{FileId, FileLongId, VirtualFile};
+use cairo_lang_parser::db::ParserGroup;
 use cairo_lang_parser::parser::Parser;
 use cairo_lang_starknet::plugin::StarkNetPlugin;
 use cairo_lang_syntax::node::{SyntaxNode, TypedSyntaxNode};
+use std::path::Path;
 use std::sync::Arc;
 
 pub fn get_database_with_starknet_plugin() -> RootDatabase {
@@ -21,6 +23,39 @@ pub fn get_syntax_tree(db: &RootDatabase, content: String) -> SyntaxNode {
         name: "string_to_parse".into(),
         content: Arc::new(content.clone()),
     }));
+    parse_file(db, virtual_file, &content)
+}
+
+/// Get the AST of a file from its content, reusing the file id of the path. Intended for
+/// files parsed again when their content changes (ex: watched files), since the content
+/// of a virtual file is kept in the database for each parsed version.
+pub fn get_file_syntax_tree(db: &mut RootDatabase, path: &Path, content: String) -> SyntaxNode {
+    let file_id = db.intern_file(FileLongId::OnDisk(path.to_path_buf()));
+    db.override_file_content(file_id, Some(Arc::new(content)));
+    match db.file_syntax(file_id) {
+        Ok(syntax_file) => syntax_file.as_syntax_node(),
+        // The code has invalid syntax, parse it without validation to get a partial AST
+        Err(_) => parse_file(db, file_id, &db.file_content(file_id).unwrap_or_default()),
+    }
+}
+
+fn parse_file(db: &RootDatabase, file_id: FileId, content: &str) -> SyntaxNode {
     let mut diagnostics = DiagnosticsBuilder::new();
-    Parser::parse_file(db, &mut diagnostics, virtual_file, content.as_str()).as_syntax_node()
+    Parser::parse_file(db, &mut diagnostics, file_id, content).as_syntax_node()
+}
+
+/// Get the AST from cairo code represented as a string, parsing it only once per database.
+/// Intended for code parsed repeatedly (ex: corelib definitions), since every parsed
+/// content is kept in the database.
+pub fn get_cached_syntax_tree(db: &RootDatabase, name: &str, content: &str) -> SyntaxNode {
+    let virtual_file = db.intern_file(FileLongId::Virtual(VirtualFile {
+        parent: None,
+        name: name.into(),
+        content: Arc::new(content.into()),
+    }));
+    match db.file_syntax(virtual_file) {
+        Ok(syntax_file) => syntax_file.as_syntax_node(),
+        // The code has syntax errors, parse it without caching to get a partial AST
+        Err(_) => get_syntax_tree(db, content.into()),
+    }
 }
@@ -1,11 +1,54 @@
 // Module for handling extern types in corelib (Base Types)
+use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::SyntaxNode;
 
-#[derive(Debug)]
+use super::utils::find_children;
+
+#[derive(Debug, Clone)]
 pub struct CairoBaseType {
     pub name: String,
     pub generics: Vec<String>,
 }
 
+impl CairoBaseType {
+    /// Declare an opaque type, emitted verbatim in SRC5 types like corelib extern types
+    pub fn new(name: &str, generics_count: usize) -> CairoBaseType {
+        CairoBaseType {
+            name: name.into(),
+            generics: (0..generics_count).map(|i| format!("T{i}")).collect(),
+        }
+    }
+}
+
+/// Get the extern types declared in the syntax tree (ex: `extern type MyHandle;`)
+pub fn get_cairo_extern_types(db: &RootDatabase, syntax_tree: &SyntaxNode) -> Vec<CairoBaseType> {
+    let mut extern_types = Vec::new();
+    for node in syntax_tree.descendants(db) {
+        if SyntaxKind::ItemExternType == node.kind(db) {
+            // Look up the Extern Type name
+            let id_node = find_children(db, &node, SyntaxKind::TerminalIdentifier).unwrap();
+            let type_name = id_node.get_text_without_trivia(db);
+            let mut type_generics = Vec::new();
+
+            // Look up the Extern Type generics
+            if let Some(child) = find_children(db, &node, SyntaxKind::WrappedGenericParamList) {
+                for node in child.descendants(db) {
+                    if node.kind(db) == SyntaxKind::GenericParamType {
+                        let generic_type = node.get_text_without_trivia(db);
+                        type_generics.push(generic_type);
+                    }
+                }
+            }
+            extern_types.push(CairoBaseType {
+                name: type_name,
+                generics: type_generics,
+            });
+        }
+    }
+    extern_types
+}
+
 pub fn get_cairo_base_types() -> [CairoBaseType; 40] {
     [
         CairoBaseType {
@@ -170,9 +213,3 @@ pub fn get_cairo_base_types() -> [CairoBaseType; 40] {
         },
     ]
 }
-
-pub fn get_cairo_base_type_from_name(name: &str) -> Option<CairoBaseType> {
-    get_cairo_base_types()
-        .into_iter()
-        .find(|base_type| base_type.name == name)
-}
@@ -0,0 +1,38 @@
+// Module for handling Cairo constants
+use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::SyntaxNode;
+use num_bigint::BigUint;
+
+use super::utils::{find_children, get_literal_number_value};
+
+#[derive(Debug)]
+pub struct CairoConstant {
+    pub name: String,
+    /// None if the value is not a number literal
+    pub value: Option<BigUint>,
+    /// The ItemConstant node the constant was parsed from
+    pub node: SyntaxNode,
+}
+
+pub fn get_cairo_constants(db: &RootDatabase, syntax_tree: &SyntaxNode) -> Vec<CairoConstant> {
+    let mut cairo_constants = Vec::new();
+    for node in syntax_tree.descendants(db) {
+        if SyntaxKind::ItemConstant == node.kind(db) {
+            // Look up the Constant name
+            let id_node = find_children(db, &node, SyntaxKind::TerminalIdentifier).unwrap();
+            let constant_name = id_node.get_text_without_trivia(db);
+
+            // Look up the Constant value
+            let value = find_children(db, &node, SyntaxKind::TerminalLiteralNumber)
+                .and_then(|literal| get_literal_number_value(&literal.get_text_without_trivia(db)));
+
+            cairo_constants.push(CairoConstant {
+                name: constant_name,
+                value,
+                node,
+            });
+        }
+    }
+    cairo_constants
+}
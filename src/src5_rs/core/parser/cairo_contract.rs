@@ -0,0 +1,224 @@
+// Module for handling Cairo contract modules and the impls they expose
+use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::SyntaxNode;
+
+use super::utils::{find_children, get_path_segments, has_attribute};
+
+/// Attributes of the modules defining contracts
+const CONTRACT_ATTRIBUTES: &[&str] = &["starknet::contract", "contract"];
+/// Attributes of the impls exposed in the contract ABI
+const EXTERNAL_IMPL_ATTRIBUTES: &[&str] = &["external(v0)", "abi(embed_v0)"];
+
+#[derive(Debug)]
+pub struct CairoImpl {
+    pub name: String,
+    /// Path of the implemented trait, without generic arguments (ex: [super, IAccount])
+    pub trait_path: Vec<String>,
+    /// Names of the functions defined in the impl body
+    pub functions: Vec<String>,
+    /// Name of the impl embedded in contracts, from `#[embeddable_as(<NAME>)]` in components
+    pub embeddable_as: Option<String>,
+    /// The ItemImpl node the impl was parsed from
+    pub node: SyntaxNode,
+}
+
+#[derive(Debug)]
+pub struct CairoImplAlias {
+    pub name: String,
+    /// Path of the aliased impl, without generic arguments (ex: [ERC20Component, ERC20Impl])
+    pub impl_path: Vec<String>,
+    /// The ItemImplAlias node the alias was parsed from
+    pub node: SyntaxNode,
+}
+
+#[derive(Debug)]
+pub struct CairoContract {
+    pub name: String,
+    /// Impls exposed in the contract ABI
+    pub impls: Vec<CairoImpl>,
+    /// Impls embedded in the contract ABI (ex: from components)
+    pub impl_aliases: Vec<CairoImplAlias>,
+    /// The ItemModule node the contract was parsed from
+    pub node: SyntaxNode,
+}
+
+/// Get the contract modules, with the impls exposed through `#[external(v0)]` or
+/// `#[abi(embed_v0)]`
+pub fn get_cairo_contracts(db: &RootDatabase, syntax_tree: &SyntaxNode) -> Vec<CairoContract> {
+    let mut contracts = Vec::new();
+    for node in syntax_tree.descendants(db) {
+        if SyntaxKind::ItemModule == node.kind(db)
+            && CONTRACT_ATTRIBUTES
+                .iter()
+                .any(|attribute| has_attribute(db, &node, attribute))
+        {
+            let Some(module_body) = find_children(db, &node, SyntaxKind::ModuleBody) else {
+                continue;
+            };
+            let id_node = find_children(db, &node, SyntaxKind::TerminalIdentifier).unwrap();
+            let mut impls = Vec::new();
+            let mut impl_aliases = Vec::new();
+
+            // Look up the exposed impls in the module items
+            let items = find_children(db, &module_body, SyntaxKind::ItemList).unwrap();
+            for item in items.children(db) {
+                if !EXTERNAL_IMPL_ATTRIBUTES
+                    .iter()
+                    .any(|attribute| has_attribute(db, &item, attribute))
+                {
+                    continue;
+                }
+                match item.kind(db) {
+                    SyntaxKind::ItemImpl => impls.push(get_cairo_impl(db, item)),
+                    SyntaxKind::ItemImplAlias => {
+                        let id_node =
+                            find_children(db, &item, SyntaxKind::TerminalIdentifier).unwrap();
+                        let path_node = find_children(db, &item, SyntaxKind::ExprPath).unwrap();
+                        impl_aliases.push(CairoImplAlias {
+                            name: id_node.get_text_without_trivia(db),
+                            impl_path: get_path_segments(db, &path_node),
+                            node: item,
+                        });
+                    }
+                    _ => {}
+                }
+            }
+            contracts.push(CairoContract {
+                name: id_node.get_text_without_trivia(db),
+                impls,
+                impl_aliases,
+                node,
+            });
+        }
+    }
+    contracts
+}
+
+/// Get every impl in the syntax tree, exposed or not
+pub fn get_cairo_impls(db: &RootDatabase, syntax_tree: &SyntaxNode) -> Vec<CairoImpl> {
+    syntax_tree
+        .descendants(db)
+        .filter(|node| SyntaxKind::ItemImpl == node.kind(db))
+        .map(|node| get_cairo_impl(db, node))
+        .collect()
+}
+
+fn get_cairo_impl(db: &RootDatabase, node: SyntaxNode) -> CairoImpl {
+    let id_node = find_children(db, &node, SyntaxKind::TerminalIdentifier).unwrap();
+    let path_node = find_children(db, &node, SyntaxKind::ExprPath).unwrap();
+
+    // Look up the Impl functions
+    let mut functions = Vec::new();
+    if let Some(impl_body) = find_children(db, &node, SyntaxKind::ImplBody) {
+        let impl_items = find_children(db, &impl_body, SyntaxKind::ImplItemList).unwrap();
+        for item in impl_items.children(db) {
+            if item.kind(db) == SyntaxKind::FunctionWithBody {
+                let declaration_node =
+                    find_children(db, &item, SyntaxKind::FunctionDeclaration).unwrap();
+                let id_node =
+                    find_children(db, &declaration_node, SyntaxKind::TerminalIdentifier).unwrap();
+                functions.push(id_node.get_text_without_trivia(db));
+            }
+        }
+    }
+    CairoImpl {
+        name: id_node.get_text_without_trivia(db),
+        trait_path: get_path_segments(db, &path_node),
+        functions,
+        embeddable_as: get_embeddable_name(db, &node),
+        node,
+    }
+}
+
+/// Get the argument of the `#[embeddable_as(<NAME>)]` attribute of an impl
+fn get_embeddable_name(db: &RootDatabase, impl_node: &SyntaxNode) -> Option<String> {
+    let attribute_list = find_children(db, impl_node, SyntaxKind::AttributeList)?;
+    attribute_list.children(db).find_map(|attribute| {
+        let path_node = find_children(db, &attribute, SyntaxKind::ExprPath)?;
+        if get_path_segments(db, &path_node) != ["embeddable_as"] {
+            return None;
+        }
+        let args = find_children(db, &attribute, SyntaxKind::ArgListParenthesized)?;
+        let args = find_children(db, &args, SyntaxKind::ArgList)?;
+        Some(args.get_text_without_trivia(db))
+    })
+}
+
+/// Get the expressions of the interface ids registered by a contract: the last argument
+/// of the `register_interface` calls, and the values compared with the interface id
+/// in `supports_interface` (ex: `interface_id == IACCOUNT_ID`), given the contract module
+pub fn get_registered_ids(db: &RootDatabase, contract_node: &SyntaxNode) -> Vec<SyntaxNode> {
+    let mut registered_ids = Vec::new();
+    for node in contract_node.descendants(db) {
+        match node.kind(db) {
+            SyntaxKind::ExprFunctionCall => {
+                let path_node = find_children(db, &node, SyntaxKind::ExprPath).unwrap();
+                if get_path_segments(db, &path_node).last().map(String::as_str)
+                    != Some("register_interface")
+                {
+                    continue;
+                }
+                let last_arg = find_children(db, &node, SyntaxKind::ArgListParenthesized)
+                    .and_then(|args| find_children(db, &args, SyntaxKind::ArgList))
+                    .and_then(|args| {
+                        args.children(db)
+                            .filter(|arg| arg.kind(db) == SyntaxKind::Arg)
+                            .last()
+                    });
+                // The value is the last child of the argument clause
+                if let Some(arg_clause) = last_arg.and_then(|arg| arg.children(db).last()) {
+                    if let Some(value) = arg_clause.children(db).last() {
+                        registered_ids.push(value);
+                    }
+                }
+            }
+            SyntaxKind::FunctionWithBody => {
+                let declaration_node =
+                    find_children(db, &node, SyntaxKind::FunctionDeclaration).unwrap();
+                let id_node =
+                    find_children(db, &declaration_node, SyntaxKind::TerminalIdentifier).unwrap();
+                if id_node.get_text_without_trivia(db) != "supports_interface" {
+                    continue;
+                }
+                let params = get_param_names(db, &declaration_node);
+                for expr in node.descendants(db) {
+                    if expr.kind(db) != SyntaxKind::ExprBinary {
+                        continue;
+                    }
+                    let children: Vec<_> = expr.children(db).collect();
+                    let [lhs, op, rhs] = children.as_slice() else {
+                        continue;
+                    };
+                    if op.kind(db) != SyntaxKind::TerminalEqEq {
+                        continue;
+                    }
+                    // Keep the side that is not the interface id parameter
+                    let is_param = |side: &SyntaxNode| {
+                        side.kind(db) == SyntaxKind::ExprPath
+                            && params.contains(&side.clone().get_text_without_trivia(db))
+                    };
+                    if is_param(lhs) {
+                        registered_ids.push(rhs.clone());
+                    } else if is_param(rhs) {
+                        registered_ids.push(lhs.clone());
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    registered_ids
+}
+
+fn get_param_names(db: &RootDatabase, declaration_node: &SyntaxNode) -> Vec<String> {
+    let signature_node =
+        find_children(db, declaration_node, SyntaxKind::FunctionSignature).unwrap();
+    let param_list = find_children(db, &signature_node, SyntaxKind::ParamList).unwrap();
+    param_list
+        .children(db)
+        .filter(|param| param.kind(db) == SyntaxKind::Param)
+        .filter_map(|param| find_children(db, &param, SyntaxKind::TerminalIdentifier))
+        .map(|id_node| id_node.get_text_without_trivia(db))
+        .collect()
+}
@@ -3,10 +3,10 @@ use cairo_lang_compiler::db::RootDatabase;
 use cairo_lang_syntax::node::kind::SyntaxKind;
 use cairo_lang_syntax::node::SyntaxNode;
 
-use super::ast::get_syntax_tree;
+use super::ast::get_cached_syntax_tree;
 use super::utils::find_children;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct CairoEnum {
     pub name: String,
     pub generics: Vec<String>,
@@ -14,20 +14,13 @@ pub struct CairoEnum {
 }
 
 pub fn get_corelib_enums(db: &RootDatabase) -> Vec<CairoEnum> {
-    // Get the syntax tree
-    let tree = get_syntax_tree(db, CORELIB_ENUMS.into());
+    // Get the syntax tree, parsed only once per database
+    let tree = get_cached_syntax_tree(db, "corelib_enums", CORELIB_ENUMS);
 
     get_cairo_enums_no_corelib(db, &tree)
 }
 
-pub fn get_cairo_enums(db: &RootDatabase, syntax_tree: &SyntaxNode) -> Vec<CairoEnum> {
-    let mut cairo_enums = get_cairo_enums_no_corelib(db, syntax_tree);
-    // Include corelib structs
-    cairo_enums.extend(get_corelib_enums(db));
-    cairo_enums
-}
-
-fn get_cairo_enums_no_corelib(db: &RootDatabase, syntax_tree: &SyntaxNode) -> Vec<CairoEnum> {
+pub fn get_cairo_enums_no_corelib(db: &RootDatabase, syntax_tree: &SyntaxNode) -> Vec<CairoEnum> {
     let mut cairo_enums = Vec::new();
     for node in syntax_tree.descendants(db) {
         if SyntaxKind::ItemEnum == node.kind(db) {
@@ -64,7 +57,8 @@ fn get_cairo_enums_no_corelib(db: &RootDatabase, syntax_tree: &SyntaxNode) -> Ve
     cairo_enums
 }
 
-const CORELIB_ENUMS: &str = "
+/// Definitions of the corelib enums used in SRC5 types
+pub const CORELIB_ENUMS: &str = "
 enum bool {
     True: (),
     False: (),
@@ -4,16 +4,17 @@ use cairo_lang_compiler::db::RootDatabase;
 use cairo_lang_syntax::node::kind::SyntaxKind;
 use cairo_lang_syntax::node::SyntaxNode;
 
-use super::cairo_enum::CairoEnum;
-use super::cairo_struct::CairoStruct;
 use super::utils::find_children;
 use crate::core::src5_type::SRC5Typed;
+use crate::core::type_registry::TypeRegistry;
 
 #[derive(Debug)]
 pub struct CairoNonGenericFunction {
     pub name: String,
     pub inputs_types: Vec<SyntaxNode>,
     pub return_type: Option<SyntaxNode>,
+    /// The TraitItemFunction node the function was parsed from
+    pub node: SyntaxNode,
 }
 
 impl CairoNonGenericFunction {
@@ -21,25 +22,22 @@ impl CairoNonGenericFunction {
         name: String,
         inputs_types: Vec<SyntaxNode>,
         return_type: Option<SyntaxNode>,
+        node: SyntaxNode,
     ) -> CairoNonGenericFunction {
         CairoNonGenericFunction {
             name,
             inputs_types,
             return_type,
+            node,
         }
     }
 
     /// Get the Extended Function Selector signature
-    pub fn get_efs_signature(
-        &self,
-        db: &RootDatabase,
-        cairo_structs: &[CairoStruct],
-        cairo_enums: &[CairoEnum],
-    ) -> Result<String> {
+    pub fn get_efs_signature(&self, db: &RootDatabase, registry: &TypeRegistry) -> Result<String> {
         let mut efs_signature = format!("{}(", self.name);
         // Resolve each member type
         for input in self.inputs_types.iter() {
-            efs_signature.push_str(&input.get_src5_type(db, cairo_structs, cairo_enums)?);
+            efs_signature.push_str(&input.get_src5_type(db, registry)?);
             efs_signature.push(',');
         }
         if efs_signature.ends_with(',') {
@@ -50,7 +48,7 @@ impl CairoNonGenericFunction {
         // Resolve return type
         if let Some(return_type) = &self.return_type {
             efs_signature.push_str("->");
-            efs_signature.push_str(&return_type.get_src5_type(db, cairo_structs, cairo_enums)?);
+            efs_signature.push_str(&return_type.get_src5_type(db, registry)?);
         }
         Ok(efs_signature)
     }
@@ -91,6 +89,7 @@ pub fn get_functions_from_trait_body(
                 name: function_name,
                 inputs_types,
                 return_type,
+                node,
             });
         }
     }
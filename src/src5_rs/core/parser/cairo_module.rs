@@ -0,0 +1,118 @@
+// Module for handling Cairo modules
+use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::SyntaxNode;
+
+use super::utils::find_children;
+
+/// Get the paths of the modules declared without body (ex: `mod account;`), which
+/// are defined in their own file. Each path includes the names of the enclosing
+/// inline modules, starting from the outermost one.
+pub fn get_external_modules(db: &RootDatabase, syntax_tree: &SyntaxNode) -> Vec<Vec<String>> {
+    let mut external_modules = Vec::new();
+    for node in syntax_tree.descendants(db) {
+        if SyntaxKind::ItemModule == node.kind(db)
+            && find_children(db, &node, SyntaxKind::ModuleBody).is_none()
+        {
+            let mut module_path = get_enclosing_modules(db, &node);
+            module_path.push(get_module_name(db, &node));
+            external_modules.push(module_path);
+        }
+    }
+    external_modules
+}
+
+/// Get the names of the inline modules enclosing a node, starting from the outermost one
+pub fn get_enclosing_modules(db: &RootDatabase, node: &SyntaxNode) -> Vec<String> {
+    let mut module_path = Vec::new();
+    let mut parent = node.parent();
+    while let Some(ancestor) = parent {
+        if SyntaxKind::ItemModule == ancestor.kind(db) {
+            module_path.insert(0, get_module_name(db, &ancestor));
+        }
+        parent = ancestor.parent();
+    }
+    module_path
+}
+
+/// Get the names brought into scope by the `use` items of the module enclosing a node,
+/// with the paths they refer to (ex: `use super::{ICounter, ISRC5 as ISupport};` ->
+/// [(ICounter, [super, ICounter]), (ISupport, [super, ISRC5])])
+pub fn get_module_uses(db: &RootDatabase, node: &SyntaxNode) -> Vec<(String, Vec<String>)> {
+    // The items of the innermost enclosing module, or of the file
+    let mut items = None;
+    let mut parent = node.parent();
+    while let Some(ancestor) = parent {
+        match ancestor.kind(db) {
+            SyntaxKind::ItemModule => {
+                items = find_children(db, &ancestor, SyntaxKind::ModuleBody)
+                    .and_then(|module_body| find_children(db, &module_body, SyntaxKind::ItemList));
+                break;
+            }
+            SyntaxKind::SyntaxFile => {
+                items = find_children(db, &ancestor, SyntaxKind::ItemList);
+                break;
+            }
+            _ => parent = ancestor.parent(),
+        }
+    }
+
+    let mut uses = Vec::new();
+    for item in items.iter().flat_map(|items| items.children(db)) {
+        if SyntaxKind::ItemUse == item.kind(db) {
+            for use_path in item.children(db) {
+                add_use_paths(db, &use_path, Vec::new(), &mut uses);
+            }
+        }
+    }
+    uses
+}
+
+fn add_use_paths(
+    db: &RootDatabase,
+    use_path: &SyntaxNode,
+    mut prefix: Vec<String>,
+    uses: &mut Vec<(String, Vec<String>)>,
+) {
+    match use_path.kind(db) {
+        SyntaxKind::UsePathSingle => {
+            let children: Vec<_> = use_path.children(db).collect();
+            if let (Some(segment), Some(rest)) = (children.first(), children.last()) {
+                prefix.push(get_segment_name(db, segment));
+                add_use_paths(db, rest, prefix, uses);
+            }
+        }
+        SyntaxKind::UsePathLeaf => {
+            let Some(segment) = use_path.children(db).next() else {
+                return;
+            };
+            let name = get_segment_name(db, &segment);
+            let alias = find_children(db, use_path, SyntaxKind::AliasClause)
+                .and_then(|alias| find_children(db, &alias, SyntaxKind::TerminalIdentifier))
+                .map(|id_node| id_node.get_text_without_trivia(db));
+            prefix.push(name.clone());
+            uses.push((alias.unwrap_or(name), prefix));
+        }
+        SyntaxKind::UsePathMulti => {
+            if let Some(use_paths) = find_children(db, use_path, SyntaxKind::UsePathList) {
+                for use_path in use_paths.children(db) {
+                    add_use_paths(db, &use_path, prefix.clone(), uses);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Get the name of a PathSegmentSimple or PathSegmentWithGenericArgs node
+fn get_segment_name(db: &RootDatabase, segment: &SyntaxNode) -> String {
+    match find_children(db, segment, SyntaxKind::TerminalIdentifier) {
+        Some(id_node) => id_node.get_text_without_trivia(db),
+        None => segment.clone().get_text_without_trivia(db),
+    }
+}
+
+fn get_module_name(db: &RootDatabase, module_node: &SyntaxNode) -> String {
+    let id_node = find_children(db, module_node, SyntaxKind::TerminalIdentifier).unwrap();
+    id_node.get_text_without_trivia(db)
+}
@@ -3,10 +3,10 @@ use cairo_lang_compiler::db::RootDatabase;
 use cairo_lang_syntax::node::kind::SyntaxKind;
 use cairo_lang_syntax::node::SyntaxNode;
 
-use super::ast::get_syntax_tree;
+use super::ast::get_cached_syntax_tree;
 use super::utils::find_children;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct CairoStruct {
     pub name: String,
     pub generics: Vec<String>,
@@ -14,19 +14,12 @@ pub struct CairoStruct {
 }
 
 pub fn get_corelib_structs(db: &RootDatabase) -> Vec<CairoStruct> {
-    // Get the syntax tree
-    let tree = get_syntax_tree(db, CORELIB_STRUCTS.into());
+    // Get the syntax tree, parsed only once per database
+    let tree = get_cached_syntax_tree(db, "corelib_structs", CORELIB_STRUCTS);
 
     get_cairo_structs_no_corelib(db, &tree)
 }
 
-pub fn get_cairo_structs(db: &RootDatabase, syntax_tree: &SyntaxNode) -> Vec<CairoStruct> {
-    let mut cairo_structs = get_cairo_structs_no_corelib(db, syntax_tree);
-    // Include corelib structs
-    cairo_structs.extend(get_corelib_structs(db));
-    cairo_structs
-}
-
 pub fn get_cairo_structs_no_corelib(
     db: &RootDatabase,
     syntax_tree: &SyntaxNode,
@@ -66,7 +59,8 @@ pub fn get_cairo_structs_no_corelib(
     cairo_structs
 }
 
-const CORELIB_STRUCTS: &str = "
+/// Definitions of the corelib structs used in SRC5 types
+pub const CORELIB_STRUCTS: &str = "
 struct Span<T> {
     snapshot: @Array<T>
 }
@@ -5,12 +5,17 @@ use cairo_lang_syntax::node::SyntaxNode;
 
 use super::cairo_function::get_functions_from_trait_body;
 use super::cairo_function::CairoNonGenericFunction;
-use super::utils::find_children;
+use super::utils::{find_children, has_attribute};
+
+/// Attribute of the traits defining a contract interface, generic over the contract state
+const INTERFACE_ATTRIBUTE: &str = "starknet::interface";
 
 #[derive(Debug)]
 pub struct CairoNonGenericTrait {
     pub name: String,
     pub functions: Vec<CairoNonGenericFunction>,
+    /// The ItemTrait node the trait was parsed from
+    pub node: SyntaxNode,
 }
 
 pub fn get_non_generic_traits(
@@ -33,9 +38,49 @@ pub fn get_non_generic_traits(
                 no_generic_traits.push(CairoNonGenericTrait {
                     name: trait_name,
                     functions,
+                    node,
                 });
             }
         }
     }
     no_generic_traits
 }
+
+/// Get the `#[starknet::interface]` traits, generic over the contract state, as the non
+/// generic traits their id is computed from: without the `self` parameter of the functions
+pub fn get_interface_traits(
+    db: &RootDatabase,
+    syntax_tree: &SyntaxNode,
+) -> Vec<CairoNonGenericTrait> {
+    let mut interface_traits = Vec::new();
+    for node in syntax_tree.descendants(db) {
+        if SyntaxKind::ItemTrait == node.kind(db)
+            && find_children(db, &node, SyntaxKind::WrappedGenericParamList).is_some()
+            && has_attribute(db, &node, INTERFACE_ATTRIBUTE)
+        {
+            let id_node = find_children(db, &node, SyntaxKind::TerminalIdentifier).unwrap();
+            let trait_body = find_children(db, &node, SyntaxKind::TraitBody).unwrap();
+            let mut functions = get_functions_from_trait_body(db, &trait_body);
+            for function in functions.iter_mut() {
+                function
+                    .inputs_types
+                    .retain(|type_clause| !is_self_param(db, type_clause));
+            }
+
+            interface_traits.push(CairoNonGenericTrait {
+                name: id_node.get_text_without_trivia(db),
+                functions,
+                node,
+            });
+        }
+    }
+    interface_traits
+}
+
+/// Check if a TypeClause is the type of the `self` parameter
+fn is_self_param(db: &RootDatabase, type_clause: &SyntaxNode) -> bool {
+    type_clause
+        .parent()
+        .and_then(|param| find_children(db, &param, SyntaxKind::TerminalIdentifier))
+        .is_some_and(|id_node| id_node.get_text_without_trivia(db) == "self")
+}
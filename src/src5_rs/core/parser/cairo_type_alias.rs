@@ -0,0 +1,42 @@
+// Module for handling Cairo type aliases
+use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::SyntaxNode;
+
+use super::utils::find_children;
+
+#[derive(Debug, Clone)]
+pub struct CairoTypeAlias {
+    pub name: String,
+    pub generics: Vec<String>,
+    /// The ItemTypeAlias node, whose type expression is the aliased type
+    pub aliased_type: SyntaxNode,
+}
+
+pub fn get_cairo_type_aliases(db: &RootDatabase, syntax_tree: &SyntaxNode) -> Vec<CairoTypeAlias> {
+    let mut cairo_type_aliases = Vec::new();
+    for node in syntax_tree.descendants(db) {
+        if SyntaxKind::ItemTypeAlias == node.kind(db) {
+            // Look up the Type Alias name
+            let id_node = find_children(db, &node, SyntaxKind::TerminalIdentifier).unwrap();
+            let alias_name = id_node.get_text_without_trivia(db);
+            let mut alias_generics = Vec::new();
+
+            // Look up the Type Alias generics
+            if let Some(child) = find_children(db, &node, SyntaxKind::WrappedGenericParamList) {
+                for node in child.descendants(db) {
+                    if node.kind(db) == SyntaxKind::GenericParamType {
+                        let generic_type = node.get_text_without_trivia(db);
+                        alias_generics.push(generic_type);
+                    }
+                }
+            }
+            cairo_type_aliases.push(CairoTypeAlias {
+                name: alias_name,
+                generics: alias_generics,
+                aliased_type: node,
+            });
+        }
+    }
+    cairo_type_aliases
+}
@@ -1,7 +1,104 @@
 use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_filesystem::span::{TextOffset, TextPosition};
 use cairo_lang_syntax::node::kind::SyntaxKind;
 use cairo_lang_syntax::node::SyntaxNode;
+use num_bigint::BigUint;
 
 pub fn find_children(db: &RootDatabase, node: &SyntaxNode, kind: SyntaxKind) -> Option<SyntaxNode> {
     node.children(db).find(|child| kind == child.kind(db))
 }
+
+/// Get the line and column (0 based) of an offset in the content the syntax tree was
+/// parsed from
+pub fn get_text_position(content: &str, offset: TextOffset) -> TextPosition {
+    let index = content.len() - offset.take_from(content).len();
+    let line_start = content[..index].rfind('\n').map_or(0, |i| i + 1);
+    TextPosition {
+        line: content[..index].matches('\n').count(),
+        col: content[line_start..index].chars().count(),
+    }
+}
+
+/// Get the names of the segments of an ExprPath node, without the generic arguments
+/// (ex: `super::IAccount<ContractState>` -> [super, IAccount])
+pub fn get_path_segments(db: &RootDatabase, expr_path: &SyntaxNode) -> Vec<String> {
+    let mut segments = Vec::new();
+    for segment in expr_path.children(db) {
+        match segment.kind(db) {
+            SyntaxKind::PathSegmentSimple => {
+                segments.push(segment.get_text_without_trivia(db));
+            }
+            SyntaxKind::PathSegmentWithGenericArgs => {
+                let id_node = find_children(db, &segment, SyntaxKind::TerminalIdentifier).unwrap();
+                segments.push(id_node.get_text_without_trivia(db));
+            }
+            _ => {}
+        }
+    }
+    segments
+}
+
+/// Check if an item has an attribute, ignoring whitespace (ex: `starknet::contract`
+/// or `external(v0)`)
+pub fn has_attribute(db: &RootDatabase, item: &SyntaxNode, attribute: &str) -> bool {
+    let Some(attribute_list) = find_children(db, item, SyntaxKind::AttributeList) else {
+        return false;
+    };
+    attribute_list.children(db).any(|node| {
+        let text: String = node
+            .get_text_without_trivia(db)
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .collect();
+        text == format!("#[{attribute}]")
+    })
+}
+
+/// Get the value of a number literal, ignoring the type suffix if any (ex: `0x10_u128`)
+pub fn get_literal_number_value(literal: &str) -> Option<BigUint> {
+    let literal = literal.split('_').next()?;
+    if let Some(hex) = literal.strip_prefix("0x") {
+        BigUint::parse_bytes(hex.as_bytes(), 16)
+    } else if let Some(octal) = literal.strip_prefix("0o") {
+        BigUint::parse_bytes(octal.as_bytes(), 8)
+    } else if let Some(binary) = literal.strip_prefix("0b") {
+        BigUint::parse_bytes(binary.as_bytes(), 2)
+    } else {
+        BigUint::parse_bytes(literal.as_bytes(), 10)
+    }
+}
+
+/// Convert a PascalCase name into snake_case, keeping acronyms together
+/// (ex: IERC721Metadata -> ierc721_metadata)
+pub fn to_snake_case(name: &str) -> String {
+    let mut snake_case = String::new();
+    let mut previous: Option<char> = None;
+    for c in name.chars() {
+        if let Some(previous) = previous {
+            if c.is_uppercase() && (previous.is_lowercase() || previous.is_ascii_digit()) {
+                snake_case.push('_');
+            }
+        }
+        snake_case.extend(c.to_lowercase());
+        previous = Some(c);
+    }
+    snake_case
+}
+
+/// Get the doc comment of an item, from the `///` lines right before it (without the
+/// `///` and the space after it)
+pub fn get_doc_comment(db: &RootDatabase, item: &SyntaxNode) -> Option<String> {
+    let mut doc_lines = Vec::new();
+    for line in item.get_text(db).lines() {
+        let line = line.trim();
+        if let Some(doc_line) = line.strip_prefix("///") {
+            doc_lines.push(doc_line.strip_prefix(' ').unwrap_or(doc_line).to_string());
+        } else if line.starts_with("//") {
+            // Only the comment lines right before the item are documentation
+            doc_lines.clear();
+        } else if !line.is_empty() {
+            break;
+        }
+    }
+    (!doc_lines.is_empty()).then(|| doc_lines.join("\n"))
+}
@@ -0,0 +1,165 @@
+// Module for the Cairo compiler plugin generating the interface ids of annotated traits
+use std::sync::{Arc, Mutex};
+
+use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_defs::plugin::{
+    DynGeneratedFileAuxData, MacroPlugin, PluginDiagnostic, PluginGeneratedFile, PluginResult,
+};
+use cairo_lang_semantic::plugin::{AsDynMacroPlugin, SemanticPlugin, TrivialPluginAuxData};
+use cairo_lang_syntax::node::ast::{self, OptionWrappedGenericParamList};
+use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::helpers::QueryAttrs;
+use cairo_lang_syntax::node::{SyntaxNode, Terminal, TypedSyntaxNode};
+
+use crate::core::interface::get_src5_interface;
+use crate::core::parser::ast::{get_database_with_starknet_plugin, get_syntax_tree};
+use crate::core::parser::cairo_trait::get_non_generic_traits;
+use crate::core::parser::utils::to_snake_case;
+use crate::core::type_registry::TypeRegistry;
+
+/// Attribute of the traits whose interface id is generated
+pub const SRC5_INTERFACE_ATTR: &str = "src5::interface";
+
+/// Plugin generating a `<TRAIT>_ID` constant next to each trait annotated with
+/// `#[src5::interface]` (ex: `IERC721_METADATA_ID` for `IERC721Metadata`)
+#[derive(Default)]
+#[non_exhaustive]
+pub struct SRC5Plugin {
+    /// The database of the library and the module being compiled, reused by its traits
+    cache: Mutex<Option<PluginCache>>,
+}
+
+struct PluginCache {
+    db: RootDatabase,
+    corelib_registry: TypeRegistry,
+    module: Option<ParsedModule>,
+}
+
+/// A module parsed again with the database of the library
+struct ParsedModule {
+    text: String,
+    tree: SyntaxNode,
+    registry: TypeRegistry,
+}
+
+impl std::fmt::Debug for SRC5Plugin {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SRC5Plugin").finish_non_exhaustive()
+    }
+}
+
+impl MacroPlugin for SRC5Plugin {
+    fn generate_code(&self, db: &dyn SyntaxGroup, item_ast: ast::Item) -> PluginResult {
+        match item_ast {
+            ast::Item::Trait(trait_ast) if trait_ast.has_attr(db, SRC5_INTERFACE_ATTR) => {
+                self.handle_trait(db, trait_ast)
+            }
+            // Nothing to do for other items.
+            _ => PluginResult::default(),
+        }
+    }
+}
+impl AsDynMacroPlugin for SRC5Plugin {
+    fn as_dyn_macro_plugin<'a>(self: Arc<Self>) -> Arc<dyn MacroPlugin + 'a>
+    where
+        Self: 'a,
+    {
+        self
+    }
+}
+impl SemanticPlugin for SRC5Plugin {}
+
+impl SRC5Plugin {
+    /// Compute the interface id of the trait, resolving the types from the corelib and the
+    /// file defining the trait
+    fn handle_trait(&self, db: &dyn SyntaxGroup, trait_ast: ast::ItemTrait) -> PluginResult {
+        let name = trait_ast.name(db).text(db);
+        let diagnostic = |message: String| PluginResult {
+            code: None,
+            diagnostics: vec![PluginDiagnostic {
+                stable_ptr: trait_ast.name(db).stable_ptr().untyped(),
+                message,
+            }],
+            remove_original_item: false,
+        };
+        if !matches!(
+            trait_ast.generic_params(db),
+            OptionWrappedGenericParamList::Empty(_)
+        ) {
+            return diagnostic(format!(
+                "`{SRC5_INTERFACE_ATTR}` traits must be non generic, as the interface id is \
+                 computed from the non generic functions."
+            ));
+        }
+
+        // Parse the file again with the database of the library, once per module
+        let node = trait_ast.as_syntax_node();
+        let mut root = node.clone();
+        while let Some(parent) = root.parent() {
+            root = parent;
+        }
+        let text = root.get_text(db);
+        let mut cache = self.cache.lock().unwrap();
+        let cache = cache.get_or_insert_with(|| {
+            let db = get_database_with_starknet_plugin();
+            let corelib_registry = TypeRegistry::with_corelib(&db);
+            PluginCache {
+                db,
+                corelib_registry,
+                module: None,
+            }
+        });
+        let src5_db = &cache.db;
+        if cache.module.as_ref().map(|module| &module.text) != Some(&text) {
+            let tree = get_syntax_tree(src5_db, text.clone());
+            let mut registry = cache.corelib_registry.clone();
+            registry.register_syntax_tree(src5_db, &tree);
+            cache.module = Some(ParsedModule {
+                text,
+                tree,
+                registry,
+            });
+        }
+        let ParsedModule { tree, registry, .. } = cache.module.as_ref().unwrap();
+
+        let Some(cairo_trait) = get_non_generic_traits(src5_db, tree)
+            .into_iter()
+            .find(|cairo_trait| is_same_node(&cairo_trait.node, &node, src5_db, db))
+        else {
+            return diagnostic(format!("The trait {name} can't be parsed."));
+        };
+        let interface = match get_src5_interface(src5_db, &cairo_trait, registry) {
+            Ok(interface) => interface,
+            Err(error) => {
+                return diagnostic(format!(
+                    "The interface id of {name} can't be computed: {error}. Only the corelib \
+                     types and the types defined in the same file are supported."
+                ))
+            }
+        };
+
+        PluginResult {
+            code: Some(PluginGeneratedFile {
+                name: "src5_interface".into(),
+                content: format!(
+                    "const {}_ID: felt252 = 0x{:x};\n",
+                    to_snake_case(&name).to_uppercase(),
+                    interface.id
+                ),
+                aux_data: DynGeneratedFileAuxData(Arc::new(TrivialPluginAuxData {})),
+            }),
+            diagnostics: vec![],
+            remove_original_item: false,
+        }
+    }
+}
+
+/// Check if two nodes parsed from the same text with different databases are the same
+fn is_same_node(
+    node: &SyntaxNode,
+    other: &SyntaxNode,
+    db: &dyn SyntaxGroup,
+    other_db: &dyn SyntaxGroup,
+) -> bool {
+    node.offset() == other.offset() && node.width(db) == other.width(other_db)
+}
@@ -1,6 +1,40 @@
-use cairo_lang_starknet::contract::starknet_keccak;
+// Module for computing SRC5 selectors and interface ids
 use num_bigint::BigUint;
+use sha3::{Digest, Keccak256};
 
+/// Get the starknet_keccak (Keccak256 truncated to 250 bits) of some data
+pub fn starknet_keccak(data: &[u8]) -> BigUint {
+    let mut hasher = Keccak256::new();
+    hasher.update(data);
+    let mut result = hasher.finalize();
+
+    // Truncate result to 250 bits
+    result[0] &= 3;
+    BigUint::from_bytes_be(&result)
+}
+
+/// Get the Extended Function Selector from the SRC5 signature of a function, as the
+/// starknet_keccak of the signature
 pub fn get_selector_from_signature(signature: &str) -> BigUint {
     starknet_keccak(signature.as_bytes())
 }
+
+/// Get the Starknet entry point selector of a function, as the starknet_keccak of its name
+pub fn get_entry_point_selector(name: &str) -> BigUint {
+    starknet_keccak(name.as_bytes())
+}
+
+/// Get the interface id as the XOR of the Extended Function Selectors of its functions
+pub fn get_interface_id<'a>(selectors: impl IntoIterator<Item = &'a BigUint>) -> BigUint {
+    selectors
+        .into_iter()
+        .fold(BigUint::from(0u8), |id, selector| id ^ selector)
+}
+
+/// Get the 32 big-endian bytes of a felt (ex: an interface id or a selector)
+pub fn get_felt_bytes(value: &BigUint) -> [u8; 32] {
+    let bytes = value.to_bytes_be();
+    let mut padded = [0u8; 32];
+    padded[32 - bytes.len()..].copy_from_slice(&bytes);
+    padded
+}
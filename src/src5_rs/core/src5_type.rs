@@ -4,37 +4,19 @@ use cairo_lang_compiler::db::RootDatabase;
 use cairo_lang_syntax::node::{kind::SyntaxKind, SyntaxNode};
 use std::collections::HashMap;
 
-use crate::parser::{
-    cairo_base_type::get_cairo_base_type_from_name, cairo_enum::CairoEnum,
-    cairo_struct::CairoStruct, utils::find_children,
-};
+use crate::core::type_registry::TypeRegistry;
+use crate::parser::utils::find_children;
 
 pub trait SRC5Typed {
-    fn get_src5_type(
-        &self,
-        db: &RootDatabase,
-        cairo_structs: &[CairoStruct],
-        cairo_enums: &[CairoEnum],
-    ) -> Result<String>;
+    fn get_src5_type(&self, db: &RootDatabase, registry: &TypeRegistry) -> Result<String>;
 }
 
 impl SRC5Typed for SyntaxNode {
-    fn get_src5_type(
-        &self,
-        db: &RootDatabase,
-        cairo_structs: &[CairoStruct],
-        cairo_enums: &[CairoEnum],
-    ) -> Result<String> {
+    fn get_src5_type(&self, db: &RootDatabase, registry: &TypeRegistry) -> Result<String> {
         let replacements = &mut HashMap::new();
         match self.kind(db) {
             SyntaxKind::TypeClause | SyntaxKind::ReturnTypeClause => {
-                get_src5_type_from_type_clause_nodes_kind(
-                    db,
-                    self,
-                    cairo_structs,
-                    cairo_enums,
-                    replacements,
-                )
+                get_src5_type_from_type_clause_nodes_kind(db, self, registry, replacements)
             }
             _ => {
                 bail!("Node is not a Type Clause");
@@ -47,8 +29,7 @@ impl SRC5Typed for SyntaxNode {
 fn get_src5_type_from_type_clause_nodes_kind(
     db: &RootDatabase,
     node: &SyntaxNode,
-    cairo_structs: &[CairoStruct],
-    cairo_enums: &[CairoEnum],
+    registry: &TypeRegistry,
     replacements: &mut HashMap<String, String>,
 ) -> Result<String> {
     let mut src5_type = String::new();
@@ -58,8 +39,7 @@ fn get_src5_type_from_type_clause_nodes_kind(
         src5_type.push_str(&get_src5_type_from_expr_unary_node(
             db,
             &unary_node,
-            cairo_structs,
-            cairo_enums,
+            registry,
             replacements,
         )?);
         Ok(src5_type)
@@ -69,8 +49,7 @@ fn get_src5_type_from_type_clause_nodes_kind(
         src5_type.push_str(&get_src5_type_from_expr_tuple_node(
             db,
             &expr_tuple,
-            cairo_structs,
-            cairo_enums,
+            registry,
             replacements,
         )?);
         Ok(src5_type)
@@ -80,8 +59,7 @@ fn get_src5_type_from_type_clause_nodes_kind(
         src5_type.push_str(&get_src5_type_from_expr_path_node(
             db,
             &expr_path,
-            cairo_structs,
-            cairo_enums,
+            registry,
             replacements,
         )?);
         Ok(src5_type)
@@ -95,65 +73,22 @@ fn get_src5_type_from_type_clause_nodes_kind(
 fn get_src5_type_from_expr_path_node(
     db: &RootDatabase,
     node: &SyntaxNode,
-    structs: &[CairoStruct],
-    enums: &[CairoEnum],
+    registry: &TypeRegistry,
     replacements: &mut HashMap<String, String>,
 ) -> Result<String> {
-    let mut src5_type = String::new();
-
     // Handle no generics type
     if let Some(path_segment_simple) = find_children(db, node, SyntaxKind::PathSegmentSimple) {
         let name = path_segment_simple.get_text_without_trivia(db);
 
         // Handle replacements
         if let Some(replacement) = get_replacement_from_name(&name, replacements) {
-            src5_type.push_str(&replacement);
+            Ok(replacement)
         }
         // Handle base types
-        else if let Some(base_type) = get_cairo_base_type_from_name(&name) {
-            src5_type.push_str(&base_type.name);
-        }
-        // Handle struct types
-        else if let Some(struct_type) = get_cairo_struct_from_name(&name, structs) {
-            src5_type.push('(');
-            // Resolve each member type
-            for ty in struct_type.members_types.iter() {
-                let src5_type_for_ty = get_src5_type_from_type_clause_nodes_kind(
-                    db,
-                    ty,
-                    structs,
-                    enums,
-                    replacements,
-                )?;
-                src5_type.push_str(&src5_type_for_ty);
-                src5_type.push(',');
-            }
-            if src5_type.ends_with(',') {
-                src5_type.pop(); // Remove last comma
-            }
-            src5_type.push(')');
-        }
-        // Handle enum types
-        else if let Some(enum_type) = get_cairo_enum_from_name(&name, enums) {
-            src5_type.push_str("E(");
-            // Resolve each member type
-            for ty in enum_type.variants_types.iter() {
-                let src5_type_for_ty = get_src5_type_from_type_clause_nodes_kind(
-                    db,
-                    ty,
-                    structs,
-                    enums,
-                    replacements,
-                )?;
-                src5_type.push_str(&src5_type_for_ty);
-                src5_type.push(',');
-            }
-            if src5_type.ends_with(',') {
-                src5_type.pop(); // Remove last comma
-            }
-            src5_type.push(')');
+        else if let Some(base_type) = registry.get_base_type(&name) {
+            Ok(base_type.name.clone())
         } else {
-            bail!("Unexpected Cairo type: {}", name);
+            get_src5_type_from_name(db, &name, Vec::new(), registry)
         }
     }
     // Handle type with generics
@@ -164,133 +99,111 @@ fn get_src5_type_from_expr_path_node(
             find_children(db, &path_segment_generics, SyntaxKind::TerminalIdentifier).unwrap();
         let name = id_node.get_text_without_trivia(db);
 
-        // Handle base types
-        if let Some(base_type) = get_cairo_base_type_from_name(&name) {
-            src5_type.push_str(&base_type.name);
-
-            src5_type.push('<');
-            // Resolve each generic type
-            let generic_args_node =
-                find_children(db, &path_segment_generics, SyntaxKind::GenericArgs).unwrap();
-            let generic_args_list =
-                find_children(db, &generic_args_node, SyntaxKind::GenericArgList).unwrap();
-            for node in generic_args_list.children(db) {
-                if node.kind(db) == SyntaxKind::GenericArgExpr {
-                    let src5_type_for_generic_arg = get_src5_type_from_type_clause_nodes_kind(
-                        db,
-                        &node,
-                        structs,
-                        enums,
-                        replacements,
-                    )?;
-                    src5_type.push_str(&src5_type_for_generic_arg);
-                    src5_type.push(',');
-                }
-            }
-            if src5_type.ends_with(',') {
-                src5_type.pop(); // Remove last comma
-            }
-            src5_type.push('>');
-        }
-        // Handle struct types
-        else if let Some(struct_type) = get_cairo_struct_from_name(&name, structs) {
-            src5_type.push('(');
-            // Resolve each generic type first
-            let generic_args_node =
-                find_children(db, &path_segment_generics, SyntaxKind::GenericArgs).unwrap();
-            let generic_args_list =
-                find_children(db, &generic_args_node, SyntaxKind::GenericArgList).unwrap();
-            let new_replacements = &mut HashMap::new();
-            let mut generic_index = 0;
-            for node in generic_args_list.children(db) {
-                if node.kind(db) == SyntaxKind::GenericArgExpr {
-                    let src5_type_for_generic_arg = get_src5_type_from_type_clause_nodes_kind(
-                        db,
-                        &node,
-                        structs,
-                        enums,
-                        replacements,
-                    )?;
-                    new_replacements.insert(
-                        struct_type.generics[generic_index].clone(),
-                        src5_type_for_generic_arg,
-                    );
-                    generic_index += 1;
-                }
-            }
-            // Resolve each member type with replacements
-            for ty in struct_type.members_types.iter() {
-                let src5_type_for_ty = get_src5_type_from_type_clause_nodes_kind(
+        // Resolve each generic type first
+        let generic_args_node =
+            find_children(db, &path_segment_generics, SyntaxKind::GenericArgs).unwrap();
+        let generic_args_list =
+            find_children(db, &generic_args_node, SyntaxKind::GenericArgList).unwrap();
+        let mut generic_args = Vec::new();
+        for node in generic_args_list.children(db) {
+            if node.kind(db) == SyntaxKind::GenericArgExpr {
+                generic_args.push(get_src5_type_from_type_clause_nodes_kind(
                     db,
-                    ty,
-                    structs,
-                    enums,
-                    new_replacements,
-                )?;
-                src5_type.push_str(&src5_type_for_ty);
-                src5_type.push(',');
-            }
-            if src5_type.ends_with(',') {
-                src5_type.pop(); // Remove last comma
+                    &node,
+                    registry,
+                    replacements,
+                )?);
             }
-            src5_type.push(')');
         }
-        // Handle enum types
-        else if let Some(enum_type) = get_cairo_enum_from_name(&name, enums) {
-            src5_type.push_str("E(");
-            // Resolve each generic type first
-            let generic_args_node =
-                find_children(db, &path_segment_generics, SyntaxKind::GenericArgs).unwrap();
-            let generic_args_list =
-                find_children(db, &generic_args_node, SyntaxKind::GenericArgList).unwrap();
-            let new_replacements = &mut HashMap::new();
-            let mut generic_index = 0;
-            for node in generic_args_list.children(db) {
-                if node.kind(db) == SyntaxKind::GenericArgExpr {
-                    let src5_type_for_generic_arg = get_src5_type_from_type_clause_nodes_kind(
-                        db,
-                        &node,
-                        structs,
-                        enums,
-                        replacements,
-                    )?;
-                    new_replacements.insert(
-                        enum_type.generics[generic_index].clone(),
-                        src5_type_for_generic_arg,
-                    );
-                    generic_index += 1;
-                }
-            }
-            // Resolve each variant type with replacements
-            for ty in enum_type.variants_types.iter() {
-                let src5_type_for_ty = get_src5_type_from_type_clause_nodes_kind(
-                    db,
-                    ty,
-                    structs,
-                    enums,
-                    new_replacements,
-                )?;
-                src5_type.push_str(&src5_type_for_ty);
-                src5_type.push(',');
-            }
-            if src5_type.ends_with(',') {
-                src5_type.pop(); // Remove last comma
-            }
-            src5_type.push(')');
+
+        // Handle base types
+        if let Some(base_type) = registry.get_base_type(&name) {
+            Ok(format!("{}<{}>", base_type.name, generic_args.join(",")))
         } else {
-            bail!("Unexpected Cairo type: {}", name);
+            get_src5_type_from_name(db, &name, generic_args, registry)
         }
     } else {
         bail!("Unexpected node kind");
     }
+}
+
+/// Get the SRC5 type of a struct, enum or type alias given its resolved generic arguments.
+/// The expansions are memoized in the registry, since the same types are usually found
+/// in many functions.
+fn get_src5_type_from_name(
+    db: &RootDatabase,
+    name: &str,
+    generic_args: Vec<String>,
+    registry: &TypeRegistry,
+) -> Result<String> {
+    if let Some(src5_type) = registry.get_cached_src5_type(name, &generic_args) {
+        return Ok(src5_type);
+    }
+
+    let mut src5_type = String::new();
+    // Handle struct types
+    if let Some(struct_type) = registry.get_struct(name) {
+        let new_replacements = &mut get_replacements(&struct_type.generics, &generic_args);
+        src5_type.push('(');
+        // Resolve each member type with replacements
+        for ty in struct_type.members_types.iter() {
+            let src5_type_for_ty =
+                get_src5_type_from_type_clause_nodes_kind(db, ty, registry, new_replacements)?;
+            src5_type.push_str(&src5_type_for_ty);
+            src5_type.push(',');
+        }
+        if src5_type.ends_with(',') {
+            src5_type.pop(); // Remove last comma
+        }
+        src5_type.push(')');
+    }
+    // Handle enum types
+    else if let Some(enum_type) = registry.get_enum(name) {
+        let new_replacements = &mut get_replacements(&enum_type.generics, &generic_args);
+        src5_type.push_str("E(");
+        // Resolve each variant type with replacements
+        for ty in enum_type.variants_types.iter() {
+            let src5_type_for_ty =
+                get_src5_type_from_type_clause_nodes_kind(db, ty, registry, new_replacements)?;
+            src5_type.push_str(&src5_type_for_ty);
+            src5_type.push(',');
+        }
+        if src5_type.ends_with(',') {
+            src5_type.pop(); // Remove last comma
+        }
+        src5_type.push(')');
+    }
+    // Handle type aliases
+    else if let Some(alias_type) = registry.get_type_alias(name) {
+        // The aliased type is resolved in the scope of the alias definition
+        let new_replacements = &mut get_replacements(&alias_type.generics, &generic_args);
+        src5_type.push_str(&get_src5_type_from_type_clause_nodes_kind(
+            db,
+            &alias_type.aliased_type,
+            registry,
+            new_replacements,
+        )?);
+    } else {
+        bail!("Unexpected Cairo type: {}", name);
+    }
+
+    registry.cache_src5_type(name, generic_args, src5_type.clone());
     Ok(src5_type)
 }
 
+/// Map the generic parameters of a type to the resolved generic arguments
+fn get_replacements(generics: &[String], generic_args: &[String]) -> HashMap<String, String> {
+    generics
+        .iter()
+        .cloned()
+        .zip(generic_args.iter().cloned())
+        .collect()
+}
+
 fn get_src5_type_from_expr_tuple_node(
     db: &RootDatabase,
     tuple_node: &SyntaxNode,
-    cairo_structs: &[CairoStruct],
-    cairo_enums: &[CairoEnum],
+    registry: &TypeRegistry,
     replacements: &mut HashMap<String, String>,
 ) -> Result<String> {
     let mut src5_type = String::new();
@@ -299,35 +212,20 @@ fn get_src5_type_from_expr_tuple_node(
     for node in expr_list.children(db) {
         match node.kind(db) {
             SyntaxKind::ExprPath => {
-                let src5_type_for_ty = get_src5_type_from_expr_path_node(
-                    db,
-                    &node,
-                    cairo_structs,
-                    cairo_enums,
-                    replacements,
-                )?;
+                let src5_type_for_ty =
+                    get_src5_type_from_expr_path_node(db, &node, registry, replacements)?;
                 src5_type.push_str(&src5_type_for_ty);
                 src5_type.push(',');
             }
             SyntaxKind::ExprTuple => {
-                let src5_type_for_ty = get_src5_type_from_expr_tuple_node(
-                    db,
-                    &node,
-                    cairo_structs,
-                    cairo_enums,
-                    replacements,
-                )?;
+                let src5_type_for_ty =
+                    get_src5_type_from_expr_tuple_node(db, &node, registry, replacements)?;
                 src5_type.push_str(&src5_type_for_ty);
                 src5_type.push(',');
             }
             SyntaxKind::ExprUnary => {
-                let src5_type_for_ty = get_src5_type_from_expr_unary_node(
-                    db,
-                    &node,
-                    cairo_structs,
-                    cairo_enums,
-                    replacements,
-                )?;
+                let src5_type_for_ty =
+                    get_src5_type_from_expr_unary_node(db, &node, registry, replacements)?;
                 src5_type.push_str(&src5_type_for_ty);
                 src5_type.push(',');
             }
@@ -344,8 +242,7 @@ fn get_src5_type_from_expr_tuple_node(
 fn get_src5_type_from_expr_unary_node(
     db: &RootDatabase,
     unary_node: &SyntaxNode,
-    cairo_structs: &[CairoStruct],
-    cairo_enums: &[CairoEnum],
+    registry: &TypeRegistry,
     replacements: &mut HashMap<String, String>,
 ) -> Result<String> {
     let mut src5_type = String::new();
@@ -357,8 +254,7 @@ fn get_src5_type_from_expr_unary_node(
         src5_type.push_str(&get_src5_type_from_expr_path_node(
             db,
             &expr_path_node,
-            cairo_structs,
-            cairo_enums,
+            registry,
             replacements,
         )?);
     }
@@ -367,8 +263,7 @@ fn get_src5_type_from_expr_unary_node(
         src5_type.push_str(&get_src5_type_from_expr_tuple_node(
             db,
             &expr_tuple,
-            cairo_structs,
-            cairo_enums,
+            registry,
             replacements,
         )?);
     }
@@ -377,8 +272,7 @@ fn get_src5_type_from_expr_unary_node(
         src5_type.push_str(&get_src5_type_from_expr_unary_node(
             db,
             &expr_unary,
-            cairo_structs,
-            cairo_enums,
+            registry,
             replacements,
         )?);
     } else {
@@ -387,17 +281,6 @@ fn get_src5_type_from_expr_unary_node(
     Ok(src5_type)
 }
 
-fn get_cairo_struct_from_name<'a>(
-    name: &str,
-    structs: &'a [CairoStruct],
-) -> Option<&'a CairoStruct> {
-    structs.iter().find(|&enum_type| enum_type.name == name)
-}
-
-fn get_cairo_enum_from_name<'a>(name: &str, enums: &'a [CairoEnum]) -> Option<&'a CairoEnum> {
-    enums.iter().find(|&enum_type| enum_type.name == name)
-}
-
 fn get_replacement_from_name(name: &str, replacements: &HashMap<String, String>) -> Option<String> {
     if let Some(replacement) = replacements.get(name) {
         return Some(replacement.clone());
@@ -0,0 +1,139 @@
+// Module for registering the Cairo types that can be resolved into SRC5 types
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_syntax::node::SyntaxNode;
+
+use crate::parser::cairo_base_type::{get_cairo_base_types, get_cairo_extern_types, CairoBaseType};
+use crate::parser::cairo_enum::{get_cairo_enums_no_corelib, get_corelib_enums, CairoEnum};
+use crate::parser::cairo_struct::{get_cairo_structs_no_corelib, get_corelib_structs, CairoStruct};
+use crate::parser::cairo_type_alias::{get_cairo_type_aliases, CairoTypeAlias};
+
+/// Types known when resolving SRC5 types, indexed by name. Registering a type with
+/// the name of an already registered one of the same kind replaces it.
+#[derive(Debug, Default, Clone)]
+pub struct TypeRegistry {
+    base_types: HashMap<String, CairoBaseType>,
+    structs: HashMap<String, CairoStruct>,
+    enums: HashMap<String, CairoEnum>,
+    type_aliases: HashMap<String, CairoTypeAlias>,
+    /// Resolved SRC5 types, keyed by type name and resolved generic arguments
+    src5_types: RefCell<HashMap<(String, Vec<String>), String>>,
+}
+
+impl TypeRegistry {
+    /// Create an empty registry
+    pub fn new() -> TypeRegistry {
+        TypeRegistry::default()
+    }
+
+    /// Create a registry with the corelib base types, structs and enums
+    pub fn with_corelib(db: &RootDatabase) -> TypeRegistry {
+        let mut registry = TypeRegistry::new();
+        for base_type in get_cairo_base_types() {
+            registry.add_base_type(base_type);
+        }
+        for cairo_struct in get_corelib_structs(db) {
+            registry.add_struct(cairo_struct);
+        }
+        for cairo_enum in get_corelib_enums(db) {
+            registry.add_enum(cairo_enum);
+        }
+        registry
+    }
+
+    /// Register the structs, enums, type aliases and extern types defined in the syntax tree
+    pub fn register_syntax_tree(&mut self, db: &RootDatabase, syntax_tree: &SyntaxNode) {
+        for extern_type in get_cairo_extern_types(db, syntax_tree) {
+            self.add_base_type(extern_type);
+        }
+        for cairo_struct in get_cairo_structs_no_corelib(db, syntax_tree) {
+            self.add_struct(cairo_struct);
+        }
+        for cairo_enum in get_cairo_enums_no_corelib(db, syntax_tree) {
+            self.add_enum(cairo_enum);
+        }
+        for cairo_type_alias in get_cairo_type_aliases(db, syntax_tree) {
+            self.add_type_alias(cairo_type_alias);
+        }
+    }
+
+    /// Register a type emitted verbatim in SRC5 types, like the corelib extern types
+    pub fn add_base_type(&mut self, base_type: CairoBaseType) {
+        self.src5_types.get_mut().clear();
+        self.base_types.insert(base_type.name.clone(), base_type);
+    }
+
+    pub fn add_struct(&mut self, cairo_struct: CairoStruct) {
+        self.src5_types.get_mut().clear();
+        self.structs.insert(cairo_struct.name.clone(), cairo_struct);
+    }
+
+    pub fn add_enum(&mut self, cairo_enum: CairoEnum) {
+        self.src5_types.get_mut().clear();
+        self.enums.insert(cairo_enum.name.clone(), cairo_enum);
+    }
+
+    pub fn add_type_alias(&mut self, cairo_type_alias: CairoTypeAlias) {
+        self.src5_types.get_mut().clear();
+        self.type_aliases
+            .insert(cairo_type_alias.name.clone(), cairo_type_alias);
+    }
+
+    pub fn get_base_type(&self, name: &str) -> Option<&CairoBaseType> {
+        self.base_types.get(name)
+    }
+
+    pub fn get_struct(&self, name: &str) -> Option<&CairoStruct> {
+        self.structs.get(name)
+    }
+
+    pub fn get_enum(&self, name: &str) -> Option<&CairoEnum> {
+        self.enums.get(name)
+    }
+
+    pub fn get_type_alias(&self, name: &str) -> Option<&CairoTypeAlias> {
+        self.type_aliases.get(name)
+    }
+
+    /// Get the memoized SRC5 type of a struct, enum or type alias
+    pub(crate) fn get_cached_src5_type(
+        &self,
+        name: &str,
+        generic_args: &[String],
+    ) -> Option<String> {
+        self.src5_types
+            .borrow()
+            .get(&(name.to_string(), generic_args.to_vec()))
+            .cloned()
+    }
+
+    /// Memoize the SRC5 type of a struct, enum or type alias. Registering a type clears
+    /// the memoized types, since they may depend on it.
+    pub(crate) fn cache_src5_type(&self, name: &str, generic_args: Vec<String>, src5_type: String) {
+        self.src5_types
+            .borrow_mut()
+            .insert((name.to_string(), generic_args), src5_type);
+    }
+
+    /// Iterate over the registered base types, in no particular order
+    pub fn base_types(&self) -> impl Iterator<Item = &CairoBaseType> {
+        self.base_types.values()
+    }
+
+    /// Iterate over the registered structs, in no particular order
+    pub fn structs(&self) -> impl Iterator<Item = &CairoStruct> {
+        self.structs.values()
+    }
+
+    /// Iterate over the registered enums, in no particular order
+    pub fn enums(&self) -> impl Iterator<Item = &CairoEnum> {
+        self.enums.values()
+    }
+
+    /// Iterate over the registered type aliases, in no particular order
+    pub fn type_aliases(&self) -> impl Iterator<Item = &CairoTypeAlias> {
+        self.type_aliases.values()
+    }
+}
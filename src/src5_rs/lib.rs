@@ -1,3 +1,16 @@
+#[cfg(any(feature = "python", feature = "wasm"))]
+mod bindings;
 mod core;
+#[cfg(feature = "parser")]
+pub use crate::core::build;
+#[cfg(feature = "parser")]
+pub use crate::core::interface;
+#[cfg(feature = "parser")]
 pub use crate::core::parser;
+#[cfg(feature = "parser")]
+pub use crate::core::plugin;
 pub use crate::core::selector;
+#[cfg(feature = "parser")]
+pub use crate::core::src5_type;
+#[cfg(feature = "parser")]
+pub use crate::core::type_registry;
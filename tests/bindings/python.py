@@ -0,0 +1,31 @@
+# Smoke test of the Python bindings, installed with `pip install .` or `maturin develop`
+# Usage: python tests/bindings/python.py
+import src5_rs
+
+ISRC5_ID = 0x3F918D17E5EE77373B56385708F855659A07F75997F365CF87748628532A055
+
+[isrc5] = src5_rs.compute_interfaces(
+    """
+trait ISRC5 {
+    fn supports_interface(interface_id: felt252) -> bool;
+}
+"""
+)
+assert isrc5["name"] == "ISRC5"
+assert isrc5["id"] == ISRC5_ID
+assert isrc5["functions"] == [
+    {
+        "name": "supports_interface",
+        "signature": "supports_interface(felt252)->E((),())",
+        "selector": ISRC5_ID,
+        "entry_point_selector": src5_rs.get_entry_point_selector("supports_interface"),
+    }
+]
+assert src5_rs.get_interface_id([isrc5["functions"][0]["selector"]]) == ISRC5_ID
+
+try:
+    src5_rs.compute_interfaces("trait IFoo {\n    fn foo(value: Unknown);\n}\n")
+    raise AssertionError("unresolved types must raise a ValueError")
+except ValueError:
+    pass
+print("ok")
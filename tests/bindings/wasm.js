@@ -0,0 +1,25 @@
+// Smoke test of the JavaScript bindings, generated with `wasm-bindgen --target nodejs`
+// Usage: node tests/bindings/wasm.js <PACKAGE_DIR>
+const assert = require("node:assert");
+const path = require("node:path");
+
+const { computeInterfaces } = require(path.resolve(process.argv[2], "src5_rs.js"));
+
+const [isrc5] = computeInterfaces(`
+trait ISRC5 {
+    fn supports_interface(interface_id: felt252) -> bool;
+}
+`);
+assert.strictEqual(isrc5.name, "ISRC5");
+assert.strictEqual(isrc5.id, "0x3f918d17e5ee77373b56385708f855659a07f75997f365cf87748628532a055");
+assert.deepStrictEqual(isrc5.functions, [
+  {
+    name: "supports_interface",
+    signature: "supports_interface(felt252)->E((),())",
+    selector: "0x3f918d17e5ee77373b56385708f855659a07f75997f365cf87748628532a055",
+    entryPointSelector: "0xfe80f537b66d12a00b6d3c072b44afbb716e78dde5c3f0ef116ee93d3e3283",
+  },
+]);
+
+assert.throws(() => computeInterfaces("trait IFoo {\n    fn foo(value: Unknown);\n}\n"));
+console.log("ok");
@@ -0,0 +1,69 @@
+use src5_rs::build::get_interfaces_module;
+use src5_rs::interface::{get_src5_interfaces_from_files, get_src5_interfaces_from_source};
+
+const ISRC5_ID: &str = "3f918d17e5ee77373b56385708f855659a07f75997f365cf87748628532a055";
+
+/// Get the Rust array expression of the 32 big-endian bytes of a hex felt
+fn get_bytes(hex: &str) -> String {
+    let hex = format!("{hex:0>64}");
+    let bytes: Vec<_> = (0..64)
+        .step_by(2)
+        .map(|i| format!("0x{}", &hex[i..i + 2]))
+        .collect();
+    format!("[{}]", bytes.join(", "))
+}
+
+#[test]
+fn module_holds_the_id_and_selectors_bytes() {
+    let interfaces = get_src5_interfaces_from_source(
+        "trait ISRC5 {
+            fn supports_interface(interface_id: felt252) -> bool;
+        }",
+    )
+    .unwrap();
+    let module = get_interfaces_module(&interfaces).unwrap();
+
+    // The id of an interface with a single function is the selector of the function
+    let bytes = get_bytes(ISRC5_ID);
+    assert!(bytes.starts_with("[0x03, 0xf9, 0x18, 0xd1,"));
+    assert!(module.contains(&format!(
+        "pub mod isrc5 {{\n    /// Interface id: 0x{ISRC5_ID}\n    pub const ID: [u8; 32] = {bytes};\n"
+    )));
+    assert!(module.contains(&format!(
+        "        /// `supports_interface(felt252)->E((),())`: 0x{ISRC5_ID}\n\
+        \x20       pub const SUPPORTS_INTERFACE: [u8; 32] = {bytes};\n"
+    )));
+}
+
+#[test]
+fn module_rejects_interfaces_with_the_same_name() {
+    let interfaces = get_src5_interfaces_from_source(
+        "trait IFoo { fn foo(); }
+        mod inner {
+            trait IFoo { fn bar(); }
+        }",
+    )
+    .unwrap();
+    assert!(get_interfaces_module(&interfaces).is_err());
+}
+
+#[test]
+fn interfaces_from_files_include_the_declared_modules() {
+    let interfaces =
+        get_src5_interfaces_from_files(&["tests/cairo_code/modules/lib.cairo"]).unwrap();
+    let names: Vec<_> = interfaces
+        .iter()
+        .map(|interface| interface.name.as_str())
+        .collect();
+    assert_eq!(
+        names,
+        [
+            "IRegistry",
+            "IToken",
+            "IAccount",
+            "IVault",
+            "IVault",
+            "ISigner"
+        ]
+    );
+}
@@ -0,0 +1,93 @@
+use starknet::ContractAddress;
+
+const ISRC5_ID: felt252 = 0x3f918d17e5ee77373b56385708f855659a07f75997f365cf87748628532a055;
+
+#[starknet::interface]
+trait ISRC5<TState> {
+    fn supports_interface(self: @TState, interface_id: felt252) -> bool;
+}
+
+#[starknet::interface]
+trait ICounter<TContractState> {
+    fn get_count(self: @TContractState) -> u128;
+    fn increase(ref self: TContractState, amount: u128);
+}
+
+#[starknet::interface]
+trait IOwnable<TContractState> {
+    fn owner(self: @TContractState) -> ContractAddress;
+    fn transfer_ownership(ref self: TContractState, new_owner: ContractAddress);
+}
+
+#[starknet::component]
+mod OwnableComponent {
+    use starknet::ContractAddress;
+
+    const IOWNABLE_ID: felt252 = 0x2cd4fc4c4b661299deeaa42116ae42b504f42d96acb9f5228254e70b142f365;
+
+    #[storage]
+    struct Storage {
+        owner: ContractAddress,
+    }
+
+    #[embeddable_as(OwnableImpl)]
+    impl Ownable<
+        TContractState, impl X: HasComponent<TContractState>
+    > of super::IOwnable<ComponentState<TContractState>> {
+        fn owner(self: @ComponentState<TContractState>) -> ContractAddress {
+            self.owner.read()
+        }
+
+        fn transfer_ownership(
+            ref self: ComponentState<TContractState>, new_owner: ContractAddress
+        ) {
+            self.owner.write(new_owner);
+        }
+    }
+}
+
+#[starknet::contract]
+mod Counter {
+    use super::{ICounter, ISRC5, ISRC5_ID};
+    use super::OwnableComponent;
+    use super::OwnableComponent::IOWNABLE_ID;
+
+    component!(path: OwnableComponent, storage: ownable, event: OwnableEvent);
+
+    #[abi(embed_v0)]
+    impl OwnableImpl = OwnableComponent::OwnableImpl<ContractState>;
+
+    const ICOUNTER_ID: felt252 = 0x19d7da5592ee8733a59ea145fc9b49cdd4ddef47353a01d7dca419eda025299;
+
+    #[storage]
+    struct Storage {
+        count: u128,
+        #[substorage(v0)]
+        ownable: OwnableComponent::Storage,
+    }
+
+    #[external(v0)]
+    impl CounterImpl of ICounter<ContractState> {
+        fn get_count(self: @ContractState) -> u128 {
+            self.count.read()
+        }
+
+        fn increase(ref self: ContractState, amount: u128) {
+            self.count.write(self.count.read() + amount);
+        }
+    }
+
+    #[external(v0)]
+    impl SRC5Impl of ISRC5<ContractState> {
+        fn supports_interface(self: @ContractState, interface_id: felt252) -> bool {
+            interface_id == ISRC5_ID || interface_id == ICOUNTER_ID || interface_id == IOWNABLE_ID
+        }
+    }
+
+    #[generate_trait]
+    impl InternalImpl of InternalTrait {
+        fn reset(ref self: ContractState) {
+            self.count.write(0);
+        }
+    }
+}
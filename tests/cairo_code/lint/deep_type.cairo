@@ -0,0 +1,3 @@
+trait IDeep {
+    fn foo(value: Array<Array<Array<Array<Array<felt252>>>>>);
+}
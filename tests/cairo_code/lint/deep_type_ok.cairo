@@ -0,0 +1,3 @@
+trait IShallow {
+    fn foo(value: Array<Array<Array<Array<felt252>>>>);
+}
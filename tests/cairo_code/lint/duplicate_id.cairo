@@ -0,0 +1,7 @@
+trait IFoo {
+    fn foo(value: felt252) -> bool;
+}
+
+trait IFooAgain {
+    fn foo(value: felt252) -> bool;
+}
@@ -0,0 +1,7 @@
+trait IFoo {
+    fn foo(value: felt252) -> bool;
+}
+
+trait IFooOther {
+    fn foo(value: u8) -> bool;
+}
@@ -0,0 +1 @@
+trait IEmpty {}
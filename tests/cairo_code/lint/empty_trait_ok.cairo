@@ -0,0 +1,3 @@
+trait INotEmpty {
+    fn foo();
+}
@@ -0,0 +1,22 @@
+trait IFoo {
+    fn transfer(amount: u256);
+}
+
+trait IBar {
+    fn transfer(amount: u128);
+}
+
+#[starknet::contract]
+mod Token {
+    use super::{IFoo, IBar};
+
+    #[external(v0)]
+    impl FooImpl of IFoo<ContractState> {
+        fn transfer(amount: u256) {}
+    }
+
+    // Not exposed in the contract ABI
+    impl BarImpl of IBar<ContractState> {
+        fn transfer(amount: u128) {}
+    }
+}
@@ -0,0 +1,3 @@
+trait IVault {
+    fn deposit(vault: Vault);
+}
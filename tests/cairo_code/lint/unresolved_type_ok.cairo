@@ -0,0 +1,7 @@
+struct Vault {
+    balance: u256,
+}
+
+trait IVault {
+    fn deposit(vault: Vault);
+}
@@ -0,0 +1,10 @@
+mod signers;
+
+use starknet::account::Call;
+
+type Calls = Array<Call>;
+
+trait IAccount {
+    fn __execute__(calls: Calls) -> Array<Span<felt252>>;
+    fn get_signer() -> Signer;
+}
@@ -0,0 +1,9 @@
+enum Signer {
+    Stark: felt252,
+    Secp256k1: (u256, u256),
+}
+
+trait ISigner {
+    fn get_signer() -> Signer;
+    fn get_tokens() -> Array<Token>;
+}
@@ -0,0 +1,8 @@
+mod tokens;
+mod accounts;
+mod vaults;
+
+trait IRegistry {
+    fn get_tokens(owner: ContractAddress) -> Array<Token>;
+    fn get_vault(id: u256) -> Option<Vault<u256>>;
+}
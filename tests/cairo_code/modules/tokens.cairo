@@ -0,0 +1,12 @@
+use starknet::ContractAddress;
+
+#[derive(Drop, Serde)]
+struct Token {
+    id: u256,
+    owner: ContractAddress,
+}
+
+trait IToken {
+    fn get_token(id: u256) -> Token;
+    fn find(owner: ContractAddress) -> Option<Token>;
+}
@@ -0,0 +1,15 @@
+struct Vault<T> {
+    balance: T,
+    token: Token,
+}
+
+trait IVault {
+    fn get_vault() -> Vault<u128>;
+    fn get_signers() -> Span<Signer>;
+}
+
+mod inner {
+    trait IVault {
+        fn deposit(amount: u256);
+    }
+}
@@ -0,0 +1,24 @@
+use starknet::account::Call;
+
+type Calls = Array<Call>;
+type Pair<T> = (T, T);
+type Keys = Pair<felt252>;
+type Amount = u256;
+type MaybePair<T> = Option<Pair<T>>;
+
+trait IAccount {
+    fn __execute__(calls: Calls) -> Array<Span<felt252>>;
+    fn get_keys() -> Pair<felt252>;
+}
+
+trait INested {
+    fn get_keys() -> Keys;
+    fn get_pairs(pairs: Pair<Pair<u8>>) -> Amount;
+    fn find(key: felt252) -> MaybePair<Amount>;
+}
+
+trait INestedExpanded {
+    fn get_keys() -> (felt252, felt252);
+    fn get_pairs(pairs: ((u8, u8), (u8, u8))) -> u256;
+    fn find(key: felt252) -> Option<(u256, u256)>;
+}
@@ -0,0 +1,4 @@
+trait IVault {
+    #[doc('🦀')] fn deposit(vault: Vault, amount: u256);
+    fn withdraw(amount: u256) -> Receipt;
+}
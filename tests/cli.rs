@@ -0,0 +1,504 @@
+use std::path::Path;
+use std::process::{Command, Output};
+
+use tempfile::TempDir;
+
+/// Run the src5_rs binary from a directory
+fn run(dir: &Path, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_src5_rs"))
+        .current_dir(dir)
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+/// Create a directory with the files, given their paths and contents
+fn get_project(files: &[(&str, &str)]) -> TempDir {
+    let dir = tempfile::tempdir().unwrap();
+    for (path, content) in files {
+        let path = dir.path().join(path);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, content).unwrap();
+    }
+    dir
+}
+
+fn get_stdout(output: &Output) -> String {
+    String::from_utf8(output.stdout.clone()).unwrap()
+}
+
+#[test]
+fn config_is_discovered_from_a_bare_file_name() {
+    let project = get_project(&[
+        ("src5.toml", "format = \"json\"\n"),
+        ("src/lib.cairo", "trait IFoo {\n    fn foo();\n}\n"),
+    ]);
+    let src_dir = project.path().join("src");
+
+    for input in ["lib.cairo", "./lib.cairo"] {
+        let output = run(&src_dir, &["parse", input]);
+        assert!(output.status.success());
+        let interfaces: serde_json::Value = serde_json::from_str(&get_stdout(&output)).unwrap();
+        assert_eq!(interfaces[0]["name"], "IFoo");
+    }
+}
+
+#[test]
+fn json_output_includes_the_entry_point_selectors() {
+    let project = get_project(&[(
+        "lib.cairo",
+        "trait IToken {\n    fn transfer(recipient: felt252, amount: u256) -> bool;\n}\n",
+    )]);
+    let output = run(project.path(), &["parse", "lib.cairo", "-f", "json"]);
+    assert!(output.status.success());
+
+    let interfaces: serde_json::Value = serde_json::from_str(&get_stdout(&output)).unwrap();
+    let function = &interfaces[0]["functions"][0];
+    assert_eq!(function["name"], "transfer");
+    assert_eq!(
+        function["entry_point_selector"],
+        "0x83afd3f4caedc6eebf44246fe54e38c95e3179a5ec9ea81740eca5b482d12e"
+    );
+}
+
+#[test]
+fn parallel_output_is_the_sequential_one() {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/cairo_code/modules");
+    for format in ["table", "json"] {
+        let sequential = run(&dir, &["parse", "lib.cairo", "-f", format, "-j", "1"]);
+        assert!(sequential.status.success());
+        for jobs in ["2", "4"] {
+            let parallel = run(&dir, &["parse", "lib.cairo", "-f", format, "-j", jobs]);
+            assert_eq!(parallel.status, sequential.status);
+            assert_eq!(parallel.stdout, sequential.stdout);
+        }
+    }
+}
+
+#[test]
+fn parallel_error_is_the_sequential_one() {
+    let mut files = vec![("lib.cairo".to_string(), String::new())];
+    for i in 0..8 {
+        files[0].1.push_str(&format!("mod m{i};\n"));
+        // Every other file uses an unresolved type
+        let ty = if i % 2 == 0 { "felt252" } else { "Vault" };
+        files.push((
+            format!("m{i}.cairo"),
+            format!("trait IFoo{i} {{\n    fn foo(value: {ty});\n}}\n"),
+        ));
+    }
+    let files: Vec<_> = files
+        .iter()
+        .map(|(path, content)| (path.as_str(), content.as_str()))
+        .collect();
+    let project = get_project(&files);
+
+    let sequential = run(project.path(), &["parse", "lib.cairo", "-j", "1"]);
+    assert!(!sequential.status.success());
+    let parallel = run(project.path(), &["parse", "lib.cairo", "-j", "4"]);
+    assert_eq!(parallel.status, sequential.status);
+    assert_eq!(parallel.stderr, sequential.stderr);
+}
+
+#[test]
+fn generate_skips_the_impl_of_contracts_implementing_isrc5() {
+    let project = get_project(&[(
+        "lib.cairo",
+        "trait ISRC5 {\n    fn supports_interface(interface_id: felt252) -> bool;\n}\n\
+         trait IFoo {\n    fn foo();\n}\n\
+         #[starknet::contract]\nmod Supported {\n    use super::{IFoo, ISRC5};\n\
+         \x20   #[external(v0)]\n    impl FooImpl of IFoo<ContractState> {\n        fn foo() {}\n    }\n\
+         \x20   #[external(v0)]\n    impl SRC5Impl of ISRC5<ContractState> {\n\
+         \x20       fn supports_interface(interface_id: felt252) -> bool {\n            true\n        }\n    }\n}\n\
+         #[starknet::contract]\nmod Unsupported {\n    use super::IFoo;\n\
+         \x20   #[external(v0)]\n    impl FooImpl of IFoo<ContractState> {\n        fn foo() {}\n    }\n}\n",
+    )]);
+
+    let output = run(
+        project.path(),
+        &["generate", "lib.cairo", "--contract", "Supported"],
+    );
+    assert!(output.status.success());
+    let code = get_stdout(&output);
+    assert!(code.contains("// SRC5Impl: the contract already implements ISRC5"));
+    assert!(code.contains("const IFOO_ID: felt252"));
+    assert!(!code.contains("impl SRC5Impl"));
+
+    let output = run(
+        project.path(),
+        &["generate", "lib.cairo", "--contract", "Unsupported"],
+    );
+    assert!(output.status.success());
+    assert!(get_stdout(&output).contains("impl SRC5Impl of ISRC5<ContractState>"));
+}
+
+#[test]
+fn lint_rules_report_their_fixtures_only() {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/cairo_code/lint");
+    // Rules and whether they are errors by default
+    let rules = [
+        ("empty_trait", true),
+        ("selector_clash", true),
+        ("duplicate_id", true),
+        ("deep_type", false),
+        ("unresolved_type", true),
+    ];
+    for (rule, is_error) in rules {
+        let output = run(&dir, &["lint", &format!("{rule}.cairo")]);
+        assert_eq!(output.status.success(), !is_error, "{rule}");
+        let level = if is_error { "error" } else { "warning" };
+        let diagnostics = get_stdout(&output);
+        assert_eq!(diagnostics.lines().count(), 1, "{rule}: {diagnostics}");
+        assert!(
+            diagnostics.contains(&format!(": {level}[{rule}]: ")),
+            "{rule}: {diagnostics}"
+        );
+
+        let output = run(&dir, &["lint", &format!("{rule}_ok.cairo")]);
+        assert!(output.status.success(), "{rule}_ok");
+        assert_eq!(get_stdout(&output), "", "{rule}_ok");
+    }
+}
+
+#[test]
+fn lint_rules_can_be_turned_off() {
+    let project = get_project(&[
+        ("src5.toml", "[lint]\nempty_trait = \"off\"\n"),
+        ("lib.cairo", "trait IEmpty {}\n"),
+    ]);
+    let output = run(project.path(), &["lint", "lib.cairo"]);
+    assert!(output.status.success());
+    assert_eq!(get_stdout(&output), "");
+}
+
+#[test]
+fn typescript_output_rejects_traits_sharing_a_name() {
+    let project = get_project(&[
+        ("lib.cairo", "mod a;\nmod b;\n"),
+        ("a.cairo", "trait IFoo {\n    fn foo();\n}\n"),
+        ("b.cairo", "trait IFoo {\n    fn bar();\n}\n"),
+    ]);
+    let output = run(
+        project.path(),
+        &["parse", "lib.cairo", "-f", "typescript", "-j", "1"],
+    );
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Many traits are named IFoo"));
+
+    let output = run(project.path(), &["parse", "a.cairo", "-f", "typescript"]);
+    assert!(output.status.success());
+    assert_eq!(
+        get_stdout(&output).matches("export const IFoo =").count(),
+        1
+    );
+}
+
+/// Lock the interfaces of a project, returning it with the trait of `lib.cairo` replaced
+fn get_locked_project(lib: &str) -> TempDir {
+    let project = get_project(&[
+        (
+            "lib.cairo",
+            "mod accounts;\ntrait IFoo {\n    fn foo(value: felt252);\n}\n",
+        ),
+        (
+            "accounts.cairo",
+            "trait IAccount {\n    fn execute() -> bool;\n}\n",
+        ),
+    ]);
+    assert!(run(project.path(), &["lock", "lib.cairo"]).status.success());
+    std::fs::write(project.path().join("lib.cairo"), lib).unwrap();
+    project
+}
+
+#[test]
+fn verify_passes_on_unchanged_interfaces() {
+    let project = get_locked_project(
+        "mod accounts;\n// Comments don't change the id\ntrait IFoo {\n    fn foo(value: felt252);\n}\n",
+    );
+    let output = run(project.path(), &["verify", "lib.cairo"]);
+    assert!(output.status.success());
+    assert_eq!(
+        get_stdout(&output),
+        "The 2 locked interfaces are unchanged\n"
+    );
+}
+
+#[test]
+fn verify_fails_on_changed_ids() {
+    let project = get_locked_project("mod accounts;\ntrait IFoo {\n    fn foo(value: u8);\n}\n");
+    let output = run(project.path(), &["verify", "lib.cairo"]);
+    assert!(!output.status.success());
+    let stdout = get_stdout(&output);
+    assert!(stdout.starts_with("IFoo: error: id changed from 0x"));
+    assert!(stdout.contains("  - foo(felt252)\n  + foo(u8)\n"));
+    assert!(!stdout.contains("IAccount"));
+}
+
+#[test]
+fn verify_fails_on_removed_and_unlocked_interfaces() {
+    let project =
+        get_locked_project("mod accounts;\ntrait IBar {\n    fn foo(value: felt252);\n}\n");
+    let output = run(project.path(), &["verify", "lib.cairo"]);
+    assert!(!output.status.success());
+    let stdout = get_stdout(&output);
+    assert!(stdout.contains("IFoo: error: removed (locked id 0x"));
+    assert!(stdout.contains("IBar: error: not locked (id 0x"));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Found 2 changed interfaces"));
+}
+
+/// Get the interface id of the non generic trait defined in the source code
+fn get_interface_id(source: &str) -> String {
+    let interfaces = src5_rs::interface::get_src5_interfaces_from_source(source).unwrap();
+    format!("0x{:x}", interfaces[0].id)
+}
+
+/// Get the `[name, impl, id]` of the interfaces of each contract, from the JSON output
+fn get_contracts(output: &Output) -> Vec<(String, Vec<[serde_json::Value; 3]>)> {
+    let contracts: serde_json::Value = serde_json::from_str(&get_stdout(output)).unwrap();
+    contracts
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|contract| {
+            let interfaces = contract["interfaces"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|interface| {
+                    [
+                        interface["name"].clone(),
+                        interface["impl"].clone(),
+                        interface["id"].clone(),
+                    ]
+                })
+                .collect();
+            (contract["name"].as_str().unwrap().to_string(), interfaces)
+        })
+        .collect()
+}
+
+#[test]
+fn contracts_resolve_interface_traits_and_embedded_components() {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/cairo_code");
+    let output = run(&dir, &["contracts", "contract.cairo", "-f", "json"]);
+    assert!(output.status.success());
+
+    // The ids of the interface traits are computed without the self parameter
+    let counter_id = get_interface_id(
+        "trait ICounter {\n    fn get_count() -> u128;\n    fn increase(amount: u128);\n}\n",
+    );
+    let ownable_id = get_interface_id(
+        "use starknet::ContractAddress;\n\
+         trait IOwnable {\n    fn owner() -> ContractAddress;\n    \
+         fn transfer_ownership(new_owner: ContractAddress);\n}\n",
+    );
+    let isrc5_id = "0x3f918d17e5ee77373b56385708f855659a07f75997f365cf87748628532a055";
+    assert_eq!(
+        get_contracts(&output),
+        vec![(
+            "Counter".to_string(),
+            vec![
+                ["ICounter".into(), "CounterImpl".into(), counter_id.into()],
+                ["ISRC5".into(), "SRC5Impl".into(), isrc5_id.into()],
+                ["IOwnable".into(), "OwnableImpl".into(), ownable_id.into()],
+            ]
+        )]
+    );
+}
+
+#[test]
+fn contracts_resolve_traits_by_path() {
+    let project = get_project(&[(
+        "lib.cairo",
+        "mod a {\n    #[starknet::interface]\n    trait IFoo<T> {\n        fn foo(self: @T);\n    }\n}\n\
+         mod b {\n    #[starknet::interface]\n    trait IFoo<T> {\n        fn foo(self: @T, value: u8);\n    }\n}\n\
+         #[starknet::contract]\nmod Used {\n    use super::b::IFoo;\n\
+         \x20   #[external(v0)]\n    impl FooImpl of IFoo<ContractState> {\n        fn foo(self: @ContractState, value: u8) {}\n    }\n}\n\
+         #[starknet::contract]\nmod Qualified {\n\
+         \x20   #[external(v0)]\n    impl FooImpl of super::b::IFoo<ContractState> {\n        fn foo(self: @ContractState, value: u8) {}\n    }\n\
+         \x20   #[external(v0)]\n    impl BarImpl of IBar<ContractState> {\n        fn bar(self: @ContractState) {}\n    }\n}\n",
+    )]);
+    let output = run(project.path(), &["contracts", "lib.cairo", "-f", "json"]);
+    assert!(output.status.success());
+
+    let foo_id = get_interface_id("trait IFoo {\n    fn foo(value: u8);\n}\n");
+    assert_eq!(
+        get_contracts(&output),
+        vec![
+            (
+                "Used".to_string(),
+                vec![["IFoo".into(), "FooImpl".into(), foo_id.clone().into()]]
+            ),
+            (
+                "Qualified".to_string(),
+                vec![
+                    ["IFoo".into(), "FooImpl".into(), foo_id.into()],
+                    // Traits that aren't defined in the sources have no id
+                    ["IBar".into(), "BarImpl".into(), serde_json::Value::Null],
+                ]
+            ),
+        ]
+    );
+}
+
+/// Get a project with the IFoo and IBar interfaces, the ISRC5 one, and the contract
+fn get_check_project(contract: &str) -> TempDir {
+    let foo_id = get_interface_id("trait IFoo {\n    fn foo();\n}\n");
+    let lib = format!(
+        "#[starknet::interface]\ntrait ISRC5<T> {{\n    fn supports_interface(self: @T, interface_id: felt252) -> bool;\n}}\n\
+         #[starknet::interface]\ntrait IFoo<T> {{\n    fn foo(self: @T);\n}}\n\
+         #[starknet::interface]\ntrait IBar<T> {{\n    fn bar(self: @T);\n}}\n\
+         // A constant of the same name, defined before the right one\n\
+         mod other {{\n    const IFOO_ID: felt252 = 0x1;\n}}\n\
+         mod ids {{\n    const IFOO_ID: felt252 = {foo_id};\n}}\n\
+         {contract}"
+    );
+    get_project(&[("lib.cairo", &lib)])
+}
+
+const CHECK_ISRC5_IMPL: &str = "    #[external(v0)]\n    impl SRC5Impl of super::ISRC5<ContractState> {\n\
+    \x20       fn supports_interface(self: @ContractState, interface_id: felt252) -> bool {\n\
+    \x20           interface_id == 0x3f918d17e5ee77373b56385708f855659a07f75997f365cf87748628532a055\n\
+    \x20               || interface_id == IFOO_ID\n        }\n    }\n";
+
+#[test]
+fn check_passes_on_registrations_through_constants() {
+    let project = get_check_project(&format!(
+        "#[starknet::contract]\nmod Token {{\n    use super::ids::IFOO_ID;\n\
+         \x20   #[external(v0)]\n    impl FooImpl of super::IFoo<ContractState> {{\n        fn foo(self: @ContractState) {{}}\n    }}\n\
+         {CHECK_ISRC5_IMPL}}}\n"
+    ));
+    let output = run(project.path(), &["check", "lib.cairo"]);
+    assert!(output.status.success());
+    assert_eq!(get_stdout(&output), "Contract Token: ok\n");
+}
+
+#[test]
+fn check_fails_on_missing_registrations() {
+    let project = get_check_project(&format!(
+        "#[starknet::contract]\nmod Token {{\n    use super::ids::IFOO_ID;\n\
+         \x20   #[external(v0)]\n    impl FooImpl of super::IFoo<ContractState> {{\n        fn foo(self: @ContractState) {{}}\n    }}\n\
+         \x20   #[external(v0)]\n    impl BarImpl of super::IBar<ContractState> {{\n        fn bar(self: @ContractState) {{}}\n    }}\n\
+         {CHECK_ISRC5_IMPL}}}\n"
+    ));
+    let output = run(project.path(), &["check", "lib.cairo"]);
+    assert!(!output.status.success());
+    let stdout = get_stdout(&output);
+    let lines: Vec<_> = stdout.lines().collect();
+    assert_eq!(lines.len(), 2, "{stdout}");
+    assert_eq!(lines[0], "Contract Token:");
+    assert!(lines[1].contains(": error: IBar (0x"));
+    assert!(lines[1].ends_with(") is implemented by BarImpl but not registered"));
+}
+
+#[test]
+fn check_fails_on_extra_registrations() {
+    let bar_id = get_interface_id("trait IBar {\n    fn bar();\n}\n");
+    let project = get_check_project(&format!(
+        "#[starknet::contract]\nmod Token {{\n    use super::ids::IFOO_ID;\n\
+         \x20   #[external(v0)]\n    impl FooImpl of super::IFoo<ContractState> {{\n        fn foo(self: @ContractState) {{}}\n    }}\n\
+         \x20   #[constructor]\n    fn constructor(ref self: ContractState) {{\n\
+         \x20       let mut unsafe_state = SRC5::unsafe_new_contract_state();\n\
+         \x20       SRC5::InternalImpl::register_interface(ref unsafe_state, {bar_id});\n    }}\n\
+         {CHECK_ISRC5_IMPL}}}\n"
+    ));
+    let output = run(project.path(), &["check", "lib.cairo"]);
+    assert!(!output.status.success());
+    let stdout = get_stdout(&output);
+    let lines: Vec<_> = stdout.lines().collect();
+    assert_eq!(lines.len(), 2, "{stdout}");
+    assert!(lines[1].ends_with(&format!(
+        ": error: `{bar_id}` ({bar_id}) is registered but not implemented"
+    )));
+}
+
+#[test]
+fn comply_reports_missing_extra_and_mismatched_functions() {
+    let project = get_project(&[(
+        "lib.cairo",
+        "trait ISRC6 {\n    fn __execute__(calls: Array<Call>) -> Array<Span<felt252>>;\n    \
+         fn is_valid_signature(hash: felt252, signature: Span<felt252>) -> felt252;\n    \
+         fn get_public_key() -> felt252;\n}\n\
+         struct Call {\n    to: ContractAddress,\n    selector: felt252,\n    calldata: Array<felt252>\n}\n",
+    )]);
+    let output = run(project.path(), &["comply", "lib.cairo"]);
+    assert!(!output.status.success());
+    let stdout = get_stdout(&output);
+    let lines: Vec<_> = stdout.lines().collect();
+    assert_eq!(lines[0], "ISRC6 (lib.cairo:1:7) against ISRC6:");
+    assert_eq!(
+        lines[1..6],
+        [
+            "  missing: __validate__(Array<(ContractAddress,felt252,Array<felt252>)>)->felt252",
+            "  mismatch: is_valid_signature",
+            "    expected: is_valid_signature(felt252,Array<felt252>)->felt252",
+            "    found:    is_valid_signature(felt252,(@Array<felt252>))->felt252",
+            "  extra: get_public_key()->felt252",
+        ]
+    );
+    assert!(lines[6].contains("(differs from the standard id 0x"));
+}
+
+#[test]
+fn comply_compares_the_functions_of_standards_without_id() {
+    let project = get_project(&[(
+        "lib.cairo",
+        "trait IERC20 {\n    fn name() -> felt252;\n    fn symbol() -> felt252;\n    fn decimals() -> u8;\n    \
+         fn total_supply() -> u256;\n    fn balance_of(account: ContractAddress) -> u256;\n    \
+         fn allowance(owner: ContractAddress, spender: ContractAddress) -> u256;\n    \
+         fn transfer(recipient: ContractAddress, amount: u256) -> bool;\n    \
+         fn transfer_from(sender: ContractAddress, recipient: ContractAddress, amount: u256) -> bool;\n    \
+         fn approve(spender: ContractAddress, amount: u256) -> bool;\n}\n",
+    )]);
+    let output = run(project.path(), &["comply", "lib.cairo"]);
+    assert!(output.status.success());
+    let stdout = get_stdout(&output);
+    let lines: Vec<_> = stdout.lines().collect();
+    assert_eq!(lines.len(), 2, "{stdout}");
+    assert!(lines[1].ends_with(" (no standard id)"));
+}
+
+#[test]
+fn explain_attributes_id_changes_to_nested_definitions() {
+    let old = "trait IFoo {\n    fn foo(value: Outer);\n}\n\
+               struct Outer {\n    middle: Middle,\n}\n\
+               struct Middle {\n    inner: Inner,\n}\n\
+               struct Inner {\n    value: u8,\n}\n";
+    let project = get_project(&[
+        ("old.cairo", old),
+        ("new.cairo", &old.replace("value: u8", "value: u16")),
+    ]);
+    let output = run(project.path(), &["explain", "old.cairo", "new.cairo"]);
+    assert!(output.status.success());
+    let stdout = get_stdout(&output);
+    let lines: Vec<_> = stdout.lines().collect();
+    assert!(lines[0].starts_with("IFoo: id changed from 0x"));
+    assert_eq!(
+        lines[1..],
+        [
+            "  foo:",
+            "    - foo((((u8))))",
+            "    + foo((((u16))))",
+            "    caused by struct Inner (old.cairo:10:8 -> new.cairo:10:8)",
+        ]
+    );
+}
+
+#[test]
+fn explain_reports_the_definitions_the_ids_are_computed_from() {
+    // The last definition of a name is the one used in the signatures
+    let old = "trait IFoo {\n    fn foo(value: Inner);\n}\n\
+               mod a {\n    struct Inner {\n        value: u8,\n    }\n}\n\
+               mod b {\n    struct Inner {\n        value: u8,\n    }\n}\n";
+    let new = old
+        .replacen("value: u8", "value: u16", 2)
+        .replacen("value: u16", "value: u8", 1);
+    let project = get_project(&[("old.cairo", old), ("new.cairo", &new)]);
+    let output = run(project.path(), &["explain", "old.cairo", "new.cairo"]);
+    assert!(output.status.success());
+    let stdout = get_stdout(&output);
+    assert!(
+        stdout.contains("    caused by struct Inner (old.cairo:10:12 -> new.cairo:10:12)\n"),
+        "{stdout}"
+    );
+}
@@ -0,0 +1,62 @@
+use cairo_lang_defs::plugin::MacroPlugin;
+use cairo_lang_syntax::node::ast;
+use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::TypedSyntaxNode;
+use src5_rs::interface::get_src5_interfaces_from_source;
+use src5_rs::parser::ast::{get_database_with_starknet_plugin, get_syntax_tree};
+use src5_rs::parser::utils::to_snake_case;
+use src5_rs::plugin::{SRC5Plugin, SRC5_INTERFACE_ATTR};
+
+const FIXTURES: &[&str] = &[
+    include_str!("cairo_code/account.cairo"),
+    include_str!("cairo_code/type_aliases.cairo"),
+    include_str!("cairo_code/lint/duplicate_id_ok.cairo"),
+];
+
+/// Annotate every trait of the source code with the plugin attribute
+fn get_annotated_source(source: &str) -> String {
+    source
+        .lines()
+        .map(|line| match line.trim_start().starts_with("trait ") {
+            true => format!("#[{SRC5_INTERFACE_ATTR}]\n{line}\n"),
+            false => format!("{line}\n"),
+        })
+        .collect()
+}
+
+#[test]
+fn generated_ids_are_the_computed_ones() {
+    // A single plugin, so the traits of every fixture go through its cache
+    let plugin = SRC5Plugin::default();
+    let db = get_database_with_starknet_plugin();
+    let syntax_db: &dyn SyntaxGroup = &db;
+
+    for fixture in FIXTURES {
+        let source = get_annotated_source(fixture);
+        let expected: Vec<_> = get_src5_interfaces_from_source(&source)
+            .unwrap()
+            .iter()
+            .map(|interface| {
+                format!(
+                    "const {}_ID: felt252 = 0x{:x};\n",
+                    to_snake_case(&interface.name).to_uppercase(),
+                    interface.id
+                )
+            })
+            .collect();
+
+        let tree = get_syntax_tree(&db, source);
+        let generated: Vec<_> = tree
+            .descendants(&db)
+            .filter(|node| node.kind(&db) == SyntaxKind::ItemTrait)
+            .filter_map(|node| {
+                let item = ast::Item::Trait(ast::ItemTrait::from_syntax_node(syntax_db, node));
+                plugin.generate_code(syntax_db, item).code
+            })
+            .map(|code| code.content)
+            .collect();
+        assert!(!expected.is_empty());
+        assert_eq!(generated, expected);
+    }
+}
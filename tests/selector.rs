@@ -0,0 +1,25 @@
+use num_bigint::BigUint;
+use src5_rs::selector::{get_entry_point_selector, get_interface_id, get_selector_from_signature};
+
+fn get_felt(hex: &str) -> BigUint {
+    BigUint::parse_bytes(hex.trim_start_matches("0x").as_bytes(), 16).unwrap()
+}
+
+#[test]
+fn entry_point_selector_is_the_starknet_keccak_of_the_name() {
+    assert_eq!(
+        get_entry_point_selector("transfer"),
+        get_felt("0x83afd3f4caedc6eebf44246fe54e38c95e3179a5ec9ea81740eca5b482d12e")
+    );
+}
+
+#[test]
+fn interface_id_is_the_xor_of_the_selectors() {
+    let isrc5_id = get_felt("0x3f918d17e5ee77373b56385708f855659a07f75997f365cf87748628532a055");
+    let selector = get_selector_from_signature("supports_interface(felt252)->E((),())");
+    assert_eq!(selector, isrc5_id);
+    assert_eq!(get_interface_id([&selector]), isrc5_id);
+
+    // The same function twice cancels out
+    assert_eq!(get_interface_id([&selector, &selector]), BigUint::from(0u8));
+}
@@ -0,0 +1,67 @@
+use src5_rs::interface::{get_src5_interface, SRC5Interface};
+use src5_rs::parser::ast::{get_database_with_starknet_plugin, get_syntax_tree};
+use src5_rs::parser::cairo_trait::get_non_generic_traits;
+use src5_rs::type_registry::TypeRegistry;
+
+const TYPE_ALIASES: &str = include_str!("cairo_code/type_aliases.cairo");
+
+fn get_interface(name: &str) -> SRC5Interface {
+    let db = get_database_with_starknet_plugin();
+    let tree = get_syntax_tree(&db, TYPE_ALIASES.into());
+    let mut registry = TypeRegistry::with_corelib(&db);
+    registry.register_syntax_tree(&db, &tree);
+    let cairo_trait = get_non_generic_traits(&db, &tree)
+        .into_iter()
+        .find(|cairo_trait| cairo_trait.name == name)
+        .unwrap();
+    get_src5_interface(&db, &cairo_trait, &registry).unwrap()
+}
+
+fn get_signatures(interface: &SRC5Interface) -> Vec<&str> {
+    interface
+        .functions
+        .iter()
+        .map(|function| function.signature.as_str())
+        .collect()
+}
+
+#[test]
+fn aliases_of_corelib_types_and_generic_aliases() {
+    let interface = get_interface("IAccount");
+    assert_eq!(
+        get_signatures(&interface),
+        vec![
+            "__execute__(Array<(ContractAddress,felt252,(@Array<felt252>))>)->Array<(@Array<felt252>)>",
+            "get_keys()->(felt252,felt252)",
+        ]
+    );
+    assert_eq!(
+        format!("0x{:x}", interface.id),
+        "0x26fb5137f789914b7340befa42706d51b4ee8b5312323789f74cdc836c91768"
+    );
+}
+
+#[test]
+fn nested_aliases() {
+    let interface = get_interface("INested");
+    assert_eq!(
+        get_signatures(&interface),
+        vec![
+            "get_keys()->(felt252,felt252)",
+            "get_pairs(((u8,u8),(u8,u8)))->(u128,u128)",
+            "find(felt252)->E(((u128,u128),(u128,u128)),())",
+        ]
+    );
+    assert_eq!(
+        format!("0x{:x}", interface.id),
+        "0x2c914cf6018442ae8cf0ab1ff1f3e796bfdf01e397160eceddcc60f73c9d102"
+    );
+}
+
+#[test]
+fn aliases_have_the_id_of_the_aliased_types() {
+    assert_eq!(
+        get_interface("INested").id,
+        get_interface("INestedExpanded").id
+    );
+}
@@ -0,0 +1,87 @@
+use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_syntax::node::SyntaxNode;
+use src5_rs::parser::ast::{get_database_with_starknet_plugin, get_syntax_tree};
+use src5_rs::parser::cairo_base_type::CairoBaseType;
+use src5_rs::parser::cairo_trait::get_non_generic_traits;
+use src5_rs::type_registry::TypeRegistry;
+
+const FIXTURES: [&str; 2] = [
+    include_str!("cairo_code/account.cairo"),
+    include_str!("cairo_code/type_aliases.cairo"),
+];
+
+fn get_registry(db: &RootDatabase, tree: &SyntaxNode) -> TypeRegistry {
+    let mut registry = TypeRegistry::with_corelib(db);
+    registry.register_syntax_tree(db, tree);
+    registry
+}
+
+/// Get the signatures of the functions of the traits, resolved with the registry
+fn get_signatures(db: &RootDatabase, tree: &SyntaxNode, registry: &TypeRegistry) -> Vec<String> {
+    get_non_generic_traits(db, tree)
+        .iter()
+        .flat_map(|cairo_trait| cairo_trait.functions.iter())
+        .map(|function| function.get_efs_signature(db, registry).unwrap())
+        .collect()
+}
+
+#[test]
+fn memoized_types_match_the_uncached_ones() {
+    let db = get_database_with_starknet_plugin();
+    for fixture in FIXTURES {
+        let tree = get_syntax_tree(&db, fixture.into());
+
+        // A new registry for each function, so no expansion is memoized
+        let uncached: Vec<_> = get_non_generic_traits(&db, &tree)
+            .iter()
+            .flat_map(|cairo_trait| cairo_trait.functions.iter())
+            .map(|function| {
+                let registry = get_registry(&db, &tree);
+                function.get_efs_signature(&db, &registry).unwrap()
+            })
+            .collect();
+
+        // The same registry for every function, resolving them twice
+        let registry = get_registry(&db, &tree);
+        assert_eq!(get_signatures(&db, &tree, &registry), uncached);
+        assert_eq!(get_signatures(&db, &tree, &registry), uncached);
+    }
+}
+
+#[test]
+fn registering_a_type_invalidates_the_memoized_types() {
+    let db = get_database_with_starknet_plugin();
+    let tree = get_syntax_tree(
+        &db,
+        "struct Wrapper { handle: Handle, choice: Choice, value: Value }
+        struct Handle { value: felt252 }
+        enum Choice { A: felt252 }
+        type Value = felt252;
+        trait IWrapper {
+            fn get() -> Wrapper;
+        }"
+        .into(),
+    );
+    let mut registry = get_registry(&db, &tree);
+    assert_eq!(
+        get_signatures(&db, &tree, &registry),
+        vec!["get()->((felt252),E(felt252),felt252)"]
+    );
+
+    let mut register = |code: &str, expected: &str| {
+        registry.register_syntax_tree(&db, &get_syntax_tree(&db, code.into()));
+        assert_eq!(get_signatures(&db, &tree, &registry), vec![expected]);
+    };
+    register(
+        "struct Handle { value: u8 }",
+        "get()->((u8),E(felt252),felt252)",
+    );
+    register("enum Choice { A: u8 }", "get()->((u8),E(u8),felt252)");
+    register("type Value = u8;", "get()->((u8),E(u8),u8)");
+
+    registry.add_base_type(CairoBaseType::new("Handle", 0));
+    assert_eq!(
+        get_signatures(&db, &tree, &registry),
+        vec!["get()->(Handle,E(u8),u8)"]
+    );
+}